@@ -1,8 +1,461 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::{self, CloseAccount, Mint, Revoke, SetAuthority, Token, TokenAccount, Transfer};
 
 declare_id!("11111111111111111111111111111111");
 
+/// Writes a `crowdfunding:<action> key=value ...` line to the program log.
+///
+/// Compiled in only with the `structured-logs` feature. The prefix and keys are stable,
+/// so operators can alert on validator logs without decoding events.
+macro_rules! log {
+    ($action:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        #[cfg(feature = "structured-logs")]
+        msg!(concat!("crowdfunding:", $action $(, " ", stringify!($key), "={}")*) $(, $value)*);
+    };
+}
+
+/// Layouts shared with programs that compose on top of crowdfunding via CPI.
+///
+/// Structs here are versioned: fields are only ever appended, and a `version` byte
+/// leads every struct so consumers can reject layouts they don't understand.
+pub mod types {
+    use super::*;
+
+    pub const SETTLEMENT_SUMMARY_VERSION: u8 = 2;
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+    pub enum SettlementOutcome {
+        Succeeded,
+        Failed,
+        Stalled,
+    }
+
+    /// Returned by `finalize_campaign` as instruction return data.
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+    pub struct SettlementSummary {
+        pub version: u8,
+        pub campaign: Pubkey,
+        pub creator: Pubkey,
+        pub outcome: SettlementOutcome,
+        pub target_amount: u64,
+        pub raised_amount: u64,
+        pub refunded_amount: u64,
+        pub contributors_count: u32,
+        pub end_time: i64,
+        pub finalized_at: i64,
+        // Version 2
+        pub fee_collected: u64,
+        pub matched_amount: u64,
+    }
+
+    pub const CONTRIBUTE_ARGS_VERSION: u8 = 4;
+
+    /// Arguments for `contribute_v2`. New optional fields are appended with a version bump.
+    #[derive(AnchorSerialize, Clone)]
+    pub struct ContributeArgs {
+        pub version: u8,
+        pub amount: u64,
+        pub memo_hash: Option<[u8; 32]>,
+        pub referrer: Option<Pubkey>,
+        // Version 2
+        pub client_order_id: Option<[u8; 16]>,
+        // Version 3
+        pub funding_reference: Option<Pubkey>,
+        // Version 4
+        pub variant: Option<u8>,
+    }
+
+    impl ContributeArgs {
+        pub fn from_amount(amount: u64) -> Self {
+            Self {
+                version: CONTRIBUTE_ARGS_VERSION,
+                amount,
+                memo_hash: None,
+                referrer: None,
+                client_order_id: None,
+                funding_reference: None,
+                variant: None,
+            }
+        }
+    }
+
+    // Fields are only read up to the sender's version, so older clients' payloads still decode
+    impl AnchorDeserialize for ContributeArgs {
+        fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+            let version = u8::deserialize_reader(reader)?;
+            let amount = u64::deserialize_reader(reader)?;
+            let memo_hash = Option::<[u8; 32]>::deserialize_reader(reader)?;
+            let referrer = Option::<Pubkey>::deserialize_reader(reader)?;
+            let client_order_id = if version >= 2 {
+                Option::<[u8; 16]>::deserialize_reader(reader)?
+            } else {
+                None
+            };
+            let funding_reference = if version >= 3 {
+                Option::<Pubkey>::deserialize_reader(reader)?
+            } else {
+                None
+            };
+            let variant = if version >= 4 {
+                Option::<u8>::deserialize_reader(reader)?
+            } else {
+                None
+            };
+
+            Ok(Self {
+                version,
+                amount,
+                memo_hash,
+                referrer,
+                client_order_id,
+                funding_reference,
+                variant,
+            })
+        }
+    }
+}
+
+/// Byte-level registry of every account and event the program writes, for custom indexers.
+///
+/// `SCHEMA_VERSION` is bumped whenever any layout below changes. Account sizes are the bytes
+/// the program allocates, discriminator included.
+pub mod schema {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    pub const SCHEMA_VERSION: u8 = 24;
+
+    /// Byte offsets (discriminator included) that stay fixed across schema versions, for
+    /// `getProgramAccounts` memcmp filters. New fields are only ever appended after these.
+    pub mod memcmp {
+        pub const DISCRIMINATOR_OFFSET: usize = 0;
+
+        pub const CAMPAIGN_CREATOR_OFFSET: usize = 8;
+        pub const CAMPAIGN_IS_SUCCESSFUL_OFFSET: usize = 40;
+        pub const CAMPAIGN_IS_WITHDRAWN_OFFSET: usize = 41;
+        pub const CAMPAIGN_IS_FROZEN_OFFSET: usize = 42;
+        pub const CAMPAIGN_IS_STALLED_OFFSET: usize = 43;
+        pub const CAMPAIGN_CATEGORY_OFFSET: usize = 44;
+        /// Little-endian `u32` of `end_time / 86_400`, for "ending on day N" filters.
+        pub const CAMPAIGN_END_DAY_OFFSET: usize = 45;
+        pub const CAMPAIGN_END_TIME_OFFSET: usize = 49;
+
+        pub const CONTRIBUTION_CONTRIBUTOR_OFFSET: usize = 8;
+        pub const CONTRIBUTION_CAMPAIGN_OFFSET: usize = 40;
+    }
+
+    pub struct AccountLayout {
+        pub name: &'static str,
+        pub discriminator: [u8; 8],
+        pub size: usize,
+    }
+
+    pub struct EventLayout {
+        pub name: &'static str,
+        pub discriminator: [u8; 8],
+    }
+
+    macro_rules! registry {
+        (accounts: [$($account:ident),* $(,)?], events: [$($event:ident),* $(,)?]) => {
+            pub const ACCOUNTS: &[AccountLayout] = &[
+                $(AccountLayout {
+                    name: stringify!($account),
+                    discriminator: <$account as Discriminator>::DISCRIMINATOR,
+                    size: $account::SIZE,
+                },)*
+            ];
+
+            pub const EVENTS: &[EventLayout] = &[
+                $(EventLayout {
+                    name: stringify!($event),
+                    discriminator: <$event as Discriminator>::DISCRIMINATOR,
+                },)*
+            ];
+
+            #[allow(clippy::large_enum_variant)]
+            pub enum DecodedAccount {
+                $($account($account),)*
+            }
+
+            #[allow(clippy::large_enum_variant)]
+            pub enum DecodedEvent {
+                $($event($event),)*
+            }
+
+            /// Decodes raw account data of any program account by its discriminator.
+            pub fn decode_any(data: &[u8]) -> Result<DecodedAccount> {
+                require!(data.len() >= 8, ErrorCode::AccountDiscriminatorNotFound);
+                let mut bytes = data;
+                $(if data[..8] == <$account as Discriminator>::DISCRIMINATOR {
+                    return Ok(DecodedAccount::$account($account::try_deserialize(&mut bytes)?));
+                })*
+                err!(ErrorCode::AccountDiscriminatorMismatch)
+            }
+
+            /// Decodes an event payload (discriminator followed by borsh data, as found
+            /// base64-encoded after `Program data: ` in transaction logs).
+            pub fn decode_event(data: &[u8]) -> Result<DecodedEvent> {
+                require!(data.len() >= 8, ErrorCode::InstructionDidNotDeserialize);
+                $(if data[..8] == <$event as Discriminator>::DISCRIMINATOR {
+                    let event = $event::try_from_slice(&data[8..])
+                        .map_err(|_| error!(ErrorCode::InstructionDidNotDeserialize))?;
+                    return Ok(DecodedEvent::$event(event));
+                })*
+                err!(ErrorCode::InstructionDidNotDeserialize)
+            }
+        };
+    }
+
+    registry! {
+        accounts: [
+            Campaign,
+            Contribution,
+            CustodianRegistration,
+            ComposableProgram,
+            GlobalConfig,
+            RentPool,
+            CampaignSummary,
+            SurveyCommitment,
+            CampaignTemplate,
+            Series,
+            ApprovalSet,
+            ContributorProfile,
+            Round,
+            RoundEnrollment,
+            RoundContribution,
+            TrustAttestation,
+            Boost,
+            PointsConfig,
+            TeamMember,
+            AcknowledgmentsList,
+            LocalizedMetadata,
+            SettlementReport,
+            Follow,
+            RevenueShareConfig,
+            StakePosition,
+            PendingContribution,
+            AdminAction,
+            MintTargetMinimum,
+            CampaignAnalytics,
+            CreatorProfile,
+            ContributorSketch,
+            VariantMetadata,
+            BundleAnalytics,
+            BundleMember,
+            ReleaseCondition,
+            Distribution,
+            DistributionClaim,
+            StatusFeed,
+            GrantCreator,
+        ],
+        events: [
+            CampaignCreated,
+            ContributionMade,
+            CustodialContributionMade,
+            FundsWithdrawn,
+            ContributionRefunded,
+            RentPoolFunded,
+            RentPoolClosed,
+            CampaignArchived,
+            ConfigInitialized,
+            MintAllowlistUpdated,
+            GuardianUpdated,
+            CustodianUpdated,
+            CampaignFrozen,
+            SurveyAnswersCommitted,
+            SurveyClosed,
+            ProrataRefundsStarted,
+            TemplateCreated,
+            SeriesCreated,
+            SeasonStarted,
+            FeeScheduleUpdated,
+            CampaignFeeOverrideSet,
+            RefundAddressSet,
+            InactivityTimeoutSet,
+            CampaignParametersUpdated,
+            ParametersLocked,
+            CampaignStalled,
+            WithdrawalApproversSet,
+            WithdrawalApproved,
+            ContributionCapSet,
+            ReputationTierSet,
+            CampaignFinalized,
+            ContributionTagged,
+            ComposableProgramUpdated,
+            CampaignCategorySet,
+            RoundCreated,
+            RoundFunded,
+            CampaignEnrolled,
+            RoundContributionTagged,
+            MatchDisbursed,
+            RoundRemainderReclaimed,
+            RoundWeightSourceSet,
+            TrustWeightSet,
+            PlatformMintSet,
+            BoostStaked,
+            BoostUnstaked,
+            PointsConfigUpdated,
+            PointsAccrued,
+            PointsRedeemed,
+            TeamMemberUpdated,
+            CampaignUpdatePosted,
+            CampaignMetadataEdited,
+            BackersAcknowledged,
+            LocalizedMetadataSet,
+            HolderGateSet,
+            HolderOnlySet,
+            StreakBonusSet,
+            CampaignFollowed,
+            CampaignUnfollowed,
+            VaultAuthoritiesRepaired,
+            RevenueShareConfigured,
+            RevenueShared,
+            PlatformTokensStaked,
+            PlatformTokensUnstaked,
+            RevenueShareClaimed,
+            FaqUpdated,
+            CampaignScheduled,
+            AntiSnipingSet,
+            DeadlineExtended,
+            ContributionCooldownSet,
+            ContributionQueued,
+            PendingContributionResolved,
+            ContentKeyHashSet,
+            BackerProven,
+            AdminActionRecorded,
+            TimelockUpdated,
+            AdminTransferProposed,
+            AdminTransferCancelled,
+            AdminTransferred,
+            MintMinimumTargetSet,
+            CampaignVerificationSet,
+            CharityFeeWaiverSet,
+            DelegatedContributionMade,
+            PostMortemRequirementSet,
+            PostMortemPosted,
+            SurplusPolicySet,
+            VaultReconciled,
+            VariantMetadataSet,
+            ParentCampaignSet,
+            BundleRolledUp,
+            ParentShareRouted,
+            ReleaseConditionSet,
+            ReleaseConditionCleared,
+            DistributionCreated,
+            DistributionClaimed,
+            DistributionWindowClosed,
+            RecoveryKeySet,
+            CreatorFrozen,
+            CreatorKeyRotated,
+            RefundDeferred,
+            MintAuthorityPolicySet,
+            CampaignOperatorSet,
+            CampaignCreatedByOperator,
+            GrantCreatorUpdated,
+            CampaignAccepted,
+            CampaignRejected,
+            RentReimbursementSet,
+            RentReimbursed,
+        ],
+    }
+}
+
+/// Ready-made account states for composability tests (e.g. `solana-program-test`).
+///
+/// Each builder returns a fully-populated account; `account_data` serializes it with its
+/// discriminator at the size the program allocates, ready for `ProgramTest::add_account`.
+#[cfg(feature = "fixtures")]
+pub mod fixtures {
+    use super::*;
+
+    pub const DAY: i64 = 24 * 60 * 60;
+
+    pub fn config_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"config"], &ID).0
+    }
+
+    pub fn campaign_address(creator: &Pubkey, title: &str) -> Pubkey {
+        Pubkey::find_program_address(&[b"campaign", creator.as_ref(), title.as_bytes()], &ID).0
+    }
+
+    pub fn vault_address(campaign: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault", campaign.as_ref()], &ID).0
+    }
+
+    pub fn contribution_address(campaign: &Pubkey, contributor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"contribution", campaign.as_ref(), contributor.as_ref()],
+            &ID,
+        )
+        .0
+    }
+
+    pub fn global_config(admin: Pubkey, treasury: Pubkey, fee_bps: u16) -> GlobalConfig {
+        GlobalConfig {
+            admin,
+            guardian: admin,
+            treasury,
+            fee_bps,
+            ..Default::default()
+        }
+    }
+
+    /// A campaign that started at `now` and runs for 30 days with nothing raised yet.
+    pub fn active_campaign(creator: Pubkey, title: &str, target_amount: u64, now: i64) -> Campaign {
+        Campaign {
+            creator,
+            title: title.to_string(),
+            description: format!("{} fixture", title),
+            target_amount,
+            start_time: now,
+            end_time: now + 30 * DAY,
+            ..Default::default()
+        }
+    }
+
+    /// A campaign that reached its target and is waiting for the creator to withdraw.
+    pub fn successful_campaign(creator: Pubkey, title: &str, target_amount: u64, now: i64) -> Campaign {
+        Campaign {
+            current_amount: target_amount,
+            contributors_count: 1,
+            is_successful: true,
+            succeeded_at: now,
+            ..active_campaign(creator, title, target_amount, now)
+        }
+    }
+
+    /// A campaign whose deadline passed `now` without reaching `raised` up to its target.
+    pub fn failed_campaign(creator: Pubkey, title: &str, target_amount: u64, raised: u64, now: i64) -> Campaign {
+        Campaign {
+            current_amount: raised,
+            contributors_count: if raised > 0 { 1 } else { 0 },
+            start_time: now - 31 * DAY,
+            end_time: now - DAY,
+            ..active_campaign(creator, title, target_amount, now)
+        }
+    }
+
+    pub fn contribution(campaign: Pubkey, contributor: Pubkey, amount: u64) -> Contribution {
+        Contribution {
+            contributor,
+            campaign,
+            amount,
+            ..Default::default()
+        }
+    }
+
+    /// Serializes an account with its discriminator, zero-padded to `space`.
+    pub fn account_data<T: AccountSerialize>(account: &T, space: usize) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data)?;
+        require!(data.len() <= space, ErrorCode::AccountDidNotSerialize);
+        data.resize(space, 0);
+        Ok(data)
+    }
+}
+
 #[program]
 pub mod crowdfunding {
     use super::*;
@@ -13,31 +466,75 @@ pub mod crowdfunding {
         description: String,
         target_amount: u64,
         duration_days: u64,
+        require_allowlisted_mint: bool,
+        grace_period_seconds: u64,
+        grace_max_shortfall_bps: u16,
+        max_contributors: u32,
     ) -> Result<()> {
-        let campaign = &mut ctx.accounts.campaign;
         let clock = Clock::get()?;
 
-        // Input validation
-        require!(title.len() <= 100, CrowdfundingError::TitleTooLong);
-        require!(description.len() <= 500, CrowdfundingError::DescriptionTooLong);
-        require!(target_amount > 0, CrowdfundingError::InvalidTargetAmount);
-        require!(duration_days > 0 && duration_days <= 365, CrowdfundingError::InvalidDuration);
+        let params = CampaignParams {
+            target_amount,
+            duration_days,
+            require_allowlisted_mint,
+            grace_period_seconds,
+            grace_max_shortfall_bps,
+            max_contributors,
+        };
 
-        campaign.creator = ctx.accounts.creator.key();
-        campaign.title = title;
-        campaign.description = description;
-        campaign.target_amount = target_amount;
-        campaign.current_amount = 0;
-        campaign.start_time = clock.unix_timestamp;
-        campaign.end_time = clock.unix_timestamp + (duration_days as i64 * 24 * 60 * 60);
-        campaign.is_successful = false;
-        campaign.is_withdrawn = false;
-        campaign.contributors_count = 0;
+        setup_campaign(
+            &mut ctx.accounts.campaign,
+            &ctx.accounts.config,
+            ctx.accounts.creator.key(),
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_minimum,
+            &ctx.accounts.creator_profile,
+            ctx.accounts.mint_metadata.as_deref(),
+            title,
+            description,
+            &params,
+            clock.unix_timestamp,
+        )
+    }
+
+    pub fn initialize_scheduled_campaign(
+        ctx: Context<InitializeCampaign>,
+        title: String,
+        description: String,
+        params: CampaignParams,
+        start_time: i64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(
+            start_time > clock.unix_timestamp
+                && start_time <= clock.unix_timestamp + Campaign::MAX_SCHEDULE_LEAD_SECONDS,
+            CrowdfundingError::InvalidStartTime
+        );
 
-        emit!(CampaignCreated {
+        setup_campaign(
+            &mut ctx.accounts.campaign,
+            &ctx.accounts.config,
+            ctx.accounts.creator.key(),
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_minimum,
+            &ctx.accounts.creator_profile,
+            ctx.accounts.mint_metadata.as_deref(),
+            title,
+            description,
+            &params,
+            clock.unix_timestamp,
+        )?;
+
+        // The full duration runs from the scheduled launch, not from creation
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.start_time = start_time;
+        campaign.set_end_time(start_time + (params.duration_days as i64 * 24 * 60 * 60));
+
+        emit!(CampaignScheduled {
             campaign: campaign.key(),
             creator: campaign.creator,
-            target_amount: campaign.target_amount,
+            start_time,
             end_time: campaign.end_time,
         });
 
@@ -45,82 +542,114 @@ pub mod crowdfunding {
     }
 
     pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+        // Thin shim for existing clients; new parameters only arrive through contribute_v2
+        process_contribute(ctx.accounts, types::ContributeArgs::from_amount(amount))
+    }
+
+    pub fn contribute_v2(ctx: Context<Contribute>, args: types::ContributeArgs) -> Result<()> {
+        process_contribute(ctx.accounts, args)
+    }
+
+    pub fn contribute_on_behalf(
+        ctx: Context<ContributeOnBehalf>,
+        amount: u64,
+        on_behalf_of: Pubkey,
+        identity_hash: [u8; 32],
+    ) -> Result<()> {
         let campaign = &mut ctx.accounts.campaign;
         let contribution = &mut ctx.accounts.contribution;
+        let custodian = ctx.accounts.custodian.key();
         let clock = Clock::get()?;
 
-        // Check if campaign is active
-        require!(clock.unix_timestamp < campaign.end_time, CrowdfundingError::CampaignEnded);
-        require!(amount > 0, CrowdfundingError::InvalidContributionAmount);
-        require!(!campaign.is_withdrawn, CrowdfundingError::CampaignAlreadyWithdrawn);
+        if contribution.contributor == Pubkey::default() {
+            // New custodial record
+            contribution.custodian = custodian;
+            contribution.identity_hash = identity_hash;
+        } else {
+            require!(contribution.custodian == custodian, CrowdfundingError::CustodianMismatch);
+            require!(
+                contribution.identity_hash == identity_hash,
+                CrowdfundingError::IdentityHashMismatch
+            );
+        }
 
-        // Check if we don't exceed the target
-        let new_total = campaign.current_amount
-            .checked_add(amount)
-            .ok_or(CrowdfundingError::AmountOverflow)?;
-        
-        require!(new_total <= campaign.target_amount, CrowdfundingError::ExceedsTarget);
+        require!(!campaign.requires_cooldown(amount), CrowdfundingError::CooldownRequired);
+        campaign.check_contributor_cap(contribution.amount, amount, 1)?;
+
+        credit_contribution(
+            campaign,
+            contribution,
+            &ctx.accounts.contributor_sketch,
+            on_behalf_of,
+            amount,
+            clock.unix_timestamp,
+        )?;
 
-        // Transfer tokens to campaign vault
+        // Transfer tokens from the omnibus account to campaign vault
+        check_not_frozen(&ctx.accounts.custodian_token_account, &ctx.accounts.campaign_vault)?;
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
         let cpi_accounts = Transfer {
-            from: ctx.accounts.contributor_token_account.to_account_info(),
+            from: ctx.accounts.custodian_token_account.to_account_info(),
             to: ctx.accounts.campaign_vault.to_account_info(),
-            authority: ctx.accounts.contributor.to_account_info(),
+            authority: ctx.accounts.custodian.to_account_info(),
         };
-        
+
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.campaign_vault, vault_before.checked_add(amount))?;
 
-        // Update contribution state
-        if contribution.amount == 0 {
-            // New contributor
-            contribution.contributor = ctx.accounts.contributor.key();
-            contribution.campaign = campaign.key();
-            campaign.contributors_count += 1;
-        }
-        
-        contribution.amount = contribution.amount
-            .checked_add(amount)
-            .ok_or(CrowdfundingError::AmountOverflow)?;
-        
-        campaign.current_amount = new_total;
-
-        // Check if target has been reached
-        if campaign.current_amount >= campaign.target_amount {
-            campaign.is_successful = true;
-        }
-
-        emit!(ContributionMade {
+        emit!(CustodialContributionMade {
             campaign: campaign.key(),
-            contributor: ctx.accounts.contributor.key(),
+            custodian,
+            on_behalf_of,
+            identity_hash,
             amount,
-            total_raised: campaign.current_amount,
+            decimals: campaign.decimals,
         });
 
         Ok(())
     }
 
     pub fn withdraw_funds(ctx: Context<WithdrawFunds>) -> Result<()> {
+        // Campaigns with an approver list must go through execute_withdrawal
+        require!(
+            ctx.accounts.campaign.approval_threshold == 0,
+            CrowdfundingError::WithdrawalApprovalRequired
+        );
+
+        process_withdrawal(ctx.accounts, ctx.bumps.campaign_vault)
+    }
+
+    pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
         let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
         let clock = Clock::get()?;
 
-        // Check permissions
+        // Check refund conditions (a stalled campaign re-opens refunds despite its success)
+        if !campaign.is_stalled {
+            require!(
+                campaign.has_ended(clock.unix_timestamp),
+                CrowdfundingError::CampaignStillActive
+            );
+            
+            require!(!campaign.is_successful, CrowdfundingError::CampaignWasSuccessful);
+        }
+        require!(contribution.amount > 0, CrowdfundingError::NoContributionToRefund);
         require!(
-            campaign.creator == ctx.accounts.creator.key(),
-            CrowdfundingError::UnauthorizedWithdrawal
+            contribution.refund_authority() == ctx.accounts.contributor.key(),
+            CrowdfundingError::UnauthorizedRefund
         );
-
-        // Check withdrawal conditions
+        // A frozen destination would fail the transfer; defer_refund sets the amount aside
         require!(
-            campaign.is_successful || clock.unix_timestamp >= campaign.end_time,
-            CrowdfundingError::WithdrawalConditionsNotMet
+            !ctx.accounts.contributor_token_account.is_frozen(),
+            CrowdfundingError::RefundDestinationFrozen
         );
 
-        require!(!campaign.is_withdrawn, CrowdfundingError::AlreadyWithdrawn);
-
-        let amount_to_withdraw = ctx.accounts.campaign_vault.amount;
-        require!(amount_to_withdraw > 0, CrowdfundingError::NoFundsToWithdraw);
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        let refund_amount = campaign.refund_amount_for(contribution.amount, vault_before)?;
 
         // Seeds for PDA vault
         let campaign_key = campaign.key();
@@ -131,43 +660,179 @@ pub mod crowdfunding {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        // Transfer funds to campaign creator
+        // Transfer refund to contributor
         let cpi_accounts = Transfer {
             from: ctx.accounts.campaign_vault.to_account_info(),
-            to: ctx.accounts.creator_token_account.to_account_info(),
+            to: ctx.accounts.contributor_token_account.to_account_info(),
             authority: ctx.accounts.campaign_vault.to_account_info(),
         };
 
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, amount_to_withdraw)?;
+        token::transfer(cpi_ctx, refund_amount)?;
+        expect_balance_after_cpi(
+            &mut ctx.accounts.campaign_vault,
+            vault_before.checked_sub(refund_amount),
+        )?;
 
-        campaign.is_withdrawn = true;
+        campaign.total_refunded = campaign.total_refunded
+            .checked_add(contribution.amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        contribution.amount = 0;
 
-        emit!(FundsWithdrawn {
+        emit!(ContributionRefunded {
             campaign: campaign.key(),
-            creator: campaign.creator,
-            amount: amount_to_withdraw,
+            contributor: contribution.contributor,
+            amount: refund_amount,
+            decimals: campaign.decimals,
         });
+        log!(
+            "refund",
+            campaign = campaign.key(),
+            actor = contribution.contributor,
+            amount = refund_amount,
+            refunded = campaign.total_refunded,
+        );
 
         Ok(())
     }
 
-    pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
+    pub fn fund_rent_pool(
+        ctx: Context<FundRentPool>,
+        lamports: u64,
+        min_contribution: u64,
+    ) -> Result<()> {
+        let rent_pool = &mut ctx.accounts.rent_pool;
+        let clock = Clock::get()?;
+
+        require!(
+            !ctx.accounts.campaign.has_ended(clock.unix_timestamp),
+            CrowdfundingError::CampaignEnded
+        );
+        require!(lamports > 0, CrowdfundingError::InvalidSponsorshipAmount);
+
+        if rent_pool.sponsor == Pubkey::default() {
+            // First deposit; without a floor, dust contributions from fresh keys drain the pool
+            require!(min_contribution > 0, CrowdfundingError::InvalidSponsorshipAmount);
+            rent_pool.campaign = ctx.accounts.campaign.key();
+            rent_pool.sponsor = ctx.accounts.sponsor.key();
+            rent_pool.min_contribution = min_contribution;
+        }
+
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.sponsor.to_account_info(),
+            to: rent_pool.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_ctx, lamports)?;
+
+        emit!(RentPoolFunded {
+            campaign: rent_pool.campaign,
+            sponsor: ctx.accounts.sponsor.key(),
+            lamports,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_rent_pool(ctx: Context<CloseRentPool>) -> Result<()> {
         let campaign = &ctx.accounts.campaign;
-        let contribution = &mut ctx.accounts.contribution;
+        let rent_pool = &ctx.accounts.rent_pool;
         let clock = Clock::get()?;
 
-        // Check refund conditions
         require!(
-            clock.unix_timestamp >= campaign.end_time,
+            rent_pool.sponsor == ctx.accounts.sponsor.key(),
+            CrowdfundingError::UnauthorizedSponsor
+        );
+        require!(
+            campaign.is_withdrawn || campaign.has_ended(clock.unix_timestamp),
             CrowdfundingError::CampaignStillActive
         );
-        
-        require!(!campaign.is_successful, CrowdfundingError::CampaignWasSuccessful);
-        require!(contribution.amount > 0, CrowdfundingError::NoContributionToRefund);
+        // The pool names the sponsor the withdrawal reimburses, so it stays until then
+        require!(
+            !campaign.is_successful
+                || campaign.is_withdrawn
+                || campaign.is_stalled
+                || campaign.rent_reimbursement_owed == 0,
+            CrowdfundingError::RentReimbursementPending
+        );
+
+        emit!(RentPoolClosed {
+            campaign: campaign.key(),
+            sponsor: rent_pool.sponsor,
+            accounts_sponsored: rent_pool.accounts_sponsored,
+            lamports_returned: rent_pool.to_account_info().lamports(),
+        });
+
+        Ok(())
+    }
+
+    /// Sets how many tokens the vault pays the rent sponsor at withdrawal for each Contribution
+    /// account it funded. Fixed with the other economic parameters at the first contribution.
+    pub fn set_rent_reimbursement(ctx: Context<UpdateCampaign>, per_account: u64) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        campaign.rent_reimbursement_per_account = per_account;
+
+        emit!(RentReimbursementSet {
+            campaign: campaign.key(),
+            per_account,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn archive_campaign(ctx: Context<ArchiveCampaign>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let summary = &mut ctx.accounts.summary;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedArchival
+        );
+
+        // Campaign must be fully settled: funds withdrawn, or failed with every refund claimed
+        require!(
+            campaign.is_settled(clock.unix_timestamp),
+            CrowdfundingError::CampaignNotSettled
+        );
+        require!(
+            campaign.is_withdrawn || campaign.outstanding_contributions() == 0,
+            CrowdfundingError::RefundsOutstanding
+        );
+        require!(
+            ctx.accounts.campaign_vault.amount == 0,
+            CrowdfundingError::VaultNotEmpty
+        );
+
+        // Anything that still needs the live campaign account has to be wound down first
+        require!(
+            campaign.open_distributions == 0 && campaign.pending_contributions == 0,
+            CrowdfundingError::CampaignAccountsOutstanding
+        );
 
-        let refund_amount = contribution.amount;
+        summary.campaign = campaign.key();
+        summary.creator = campaign.creator;
+        summary.target_amount = campaign.target_amount;
+        summary.raised_amount = campaign.current_amount;
+        summary.contributors_count = campaign.contributors_count;
+        summary.is_successful = campaign.is_successful;
+        summary.start_time = campaign.start_time;
+        summary.end_time = campaign.end_time;
+        summary.archived_at = clock.unix_timestamp;
 
         // Seeds for PDA vault
         let campaign_key = campaign.key();
@@ -178,197 +843,9411 @@ pub mod crowdfunding {
         ];
         let signer_seeds = &[&seeds[..]];
 
-        // Transfer refund to contributor
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.campaign_vault.to_account_info(),
-            to: ctx.accounts.contributor_token_account.to_account_info(),
+        // Close the empty vault, returning its rent to the creator
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.campaign_vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
             authority: ctx.accounts.campaign_vault.to_account_info(),
         };
 
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, refund_amount)?;
+        token::close_account(cpi_ctx)?;
 
-        contribution.amount = 0;
+        emit!(CampaignArchived {
+            campaign: campaign_key,
+            summary: summary.key(),
+            raised_amount: summary.raised_amount,
+            is_successful: summary.is_successful,
+            decimals: campaign.decimals,
+        });
+        log!("archive", campaign = campaign_key, actor = ctx.accounts.creator.key(), state = "archived");
 
-        emit!(ContributionRefunded {
-            campaign: campaign.key(),
-            contributor: ctx.accounts.contributor.key(),
-            amount: refund_amount,
+        Ok(())
+    }
+
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        config.admin = ctx.accounts.admin.key();
+        config.guardian = ctx.accounts.admin.key();
+        config.mint_allowlist = Vec::new();
+        config.treasury = ctx.accounts.admin.key();
+        config.fee_bps = 0;
+        config.platform_mint = Pubkey::default();
+        config.admin_action_count = 0;
+        config.pending_admin = Pubkey::default();
+        config.admin_transfer_available_at = 0;
+        config.timelock_seconds = GlobalConfig::MIN_TIMELOCK_SECONDS;
+        config.pending_fee_bps = 0;
+        config.pending_fee_effective_at = 0;
+        config.charity_fee_waiver = false;
+        config.charity_category = 0;
+        config.require_post_mortem = false;
+        config.surplus_policy = SurplusPolicy::Treasury;
+        config.forbid_mint_authorities = false;
+        config.campaign_operator = Pubkey::default();
+
+        emit!(ConfigInitialized {
+            config: config.key(),
+            admin: config.admin,
         });
 
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-#[instruction(title: String, description: String)]
-pub struct InitializeCampaign<'info> {
-    #[account(
-        init,
-        payer = creator,
-        space = Campaign::SIZE,
-        seeds = [b"campaign", creator.key().as_ref(), title.as_bytes()],
-        bump
-    )]
-    pub campaign: Account<'info, Campaign>,
-    
-    #[account(
-        init,
-        payer = creator,
-        token::mint = mint,
-        token::authority = campaign_vault,
-        seeds = [b"vault", campaign.key().as_ref()],
-        bump
-    )]
-    pub campaign_vault: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    pub mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    pub fn add_allowed_mint(
+        ctx: Context<UpdateConfig>,
+        mint: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
 
-#[derive(Accounts)]
-pub struct Contribute<'info> {
-    #[account(mut)]
-    pub campaign: Account<'info, Campaign>,
-    
-    #[account(
-        init_if_needed,
-        payer = contributor,
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(
+            !config.mint_allowlist.contains(&mint),
+            CrowdfundingError::MintAlreadyAllowlisted
+        );
+        require!(
+            config.mint_allowlist.len() < GlobalConfig::MAX_ALLOWED_MINTS,
+            CrowdfundingError::MintAllowlistFull
+        );
+
+        config.mint_allowlist.push(mint);
+
+        emit!(MintAllowlistUpdated {
+            mint,
+            allowed: true,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::AddAllowedMint,
+            mint,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn remove_allowed_mint(
+        ctx: Context<UpdateConfig>,
+        mint: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        let position = config.mint_allowlist
+            .iter()
+            .position(|allowed| allowed == &mint)
+            .ok_or(CrowdfundingError::MintNotAllowlisted)?;
+
+        config.mint_allowlist.remove(position);
+
+        emit!(MintAllowlistUpdated {
+            mint,
+            allowed: false,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RemoveAllowedMint,
+            mint,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn register_custodian(
+        ctx: Context<RegisterCustodian>,
+        custodian: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let registration = &mut ctx.accounts.custodian_registration;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        registration.custodian = custodian;
+        registration.approved_at = Clock::get()?.unix_timestamp;
+
+        emit!(CustodianUpdated {
+            custodian,
+            approved: true,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RegisterCustodian,
+            custodian,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn remove_custodian(ctx: Context<RemoveCustodian>, memo_hash: [u8; 32]) -> Result<()> {
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        emit!(CustodianUpdated {
+            custodian: ctx.accounts.custodian_registration.custodian,
+            approved: false,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RemoveCustodian,
+            ctx.accounts.custodian_registration.custodian,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn register_composable_program(
+        ctx: Context<RegisterComposableProgram>,
+        program_id: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let registration = &mut ctx.accounts.composable_program;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        registration.program_id = program_id;
+        registration.approved_at = Clock::get()?.unix_timestamp;
+
+        emit!(ComposableProgramUpdated {
+            program_id,
+            approved: true,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RegisterComposableProgram,
+            program_id,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn remove_composable_program(
+        ctx: Context<RemoveComposableProgram>,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        emit!(ComposableProgramUpdated {
+            program_id: ctx.accounts.composable_program.program_id,
+            approved: false,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RemoveComposableProgram,
+            ctx.accounts.composable_program.program_id,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_guardian(
+        ctx: Context<UpdateConfig>,
+        guardian: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        config.guardian = guardian;
+
+        emit!(GuardianUpdated { guardian });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetGuardian,
+            guardian,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn freeze_campaign(ctx: Context<FreezeCampaign>, memo_hash: [u8; 32]) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let config = &ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.is_authority(&ctx.accounts.authority.key()),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(!campaign.is_frozen, CrowdfundingError::CampaignFrozen);
+        require!(!campaign.is_withdrawn, CrowdfundingError::CampaignAlreadyWithdrawn);
+
+        campaign.is_frozen = true;
+
+        emit!(CampaignFrozen {
+            campaign: campaign.key(),
+            authority: ctx.accounts.authority.key(),
+        });
+        log!("freeze", campaign = campaign.key(), actor = ctx.accounts.authority.key(), state = "frozen");
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            AdminActionKind::FreezeCampaign,
+            ctx.accounts.campaign.key(),
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn admin_force_refund(ctx: Context<AdminForceRefund>, memo_hash: [u8; 32]) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+        let config = &ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.is_authority(&ctx.accounts.authority.key()),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        // Forced refunds bypass the deadline, but only for frozen campaigns
+        require!(campaign.is_frozen, CrowdfundingError::CampaignNotFrozen);
+        require!(contribution.amount > 0, CrowdfundingError::NoContributionToRefund);
+
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        let refund_amount = campaign.refund_amount_for(contribution.amount, vault_before)?;
+
+        // Seeds for PDA vault
+        let campaign_key = campaign.key();
+        let seeds = &[
+            b"vault",
+            campaign_key.as_ref(),
+            &[ctx.bumps.campaign_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Transfer refund to contributor
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_vault.to_account_info(),
+            to: ctx.accounts.contributor_token_account.to_account_info(),
+            authority: ctx.accounts.campaign_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, refund_amount)?;
+        expect_balance_after_cpi(
+            &mut ctx.accounts.campaign_vault,
+            vault_before.checked_sub(refund_amount),
+        )?;
+
+        campaign.total_refunded = campaign.total_refunded
+            .checked_add(contribution.amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        contribution.amount = 0;
+
+        emit!(ContributionRefunded {
+            campaign: campaign.key(),
+            contributor: contribution.contributor,
+            amount: refund_amount,
+            decimals: campaign.decimals,
+        });
+        log!(
+            "refund",
+            campaign = campaign.key(),
+            actor = contribution.contributor,
+            amount = refund_amount,
+            refunded = campaign.total_refunded,
+        );
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.authority.key(),
+            AdminActionKind::ForceRefund,
+            ctx.accounts.contribution.contributor,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn commit_survey_answers(
+        ctx: Context<CommitSurveyAnswers>,
+        answers_hash: [u8; 32],
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let survey = &mut ctx.accounts.survey;
+        let clock = Clock::get()?;
+
+        require!(campaign.survey_closed_at == 0, CrowdfundingError::SurveyClosed);
+        require!(ctx.accounts.contribution.amount > 0, CrowdfundingError::NotABacker);
+
+        survey.campaign = campaign.key();
+        survey.backer = ctx.accounts.backer.key();
+        survey.answers_hash = answers_hash;
+        survey.committed_at = clock.unix_timestamp;
+
+        emit!(SurveyAnswersCommitted {
+            campaign: campaign.key(),
+            backer: survey.backer,
+            answers_hash,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_survey(ctx: Context<CloseSurvey>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(campaign.survey_closed_at == 0, CrowdfundingError::SurveyClosed);
+
+        campaign.survey_closed_at = clock.unix_timestamp;
+
+        emit!(SurveyClosed {
+            campaign: campaign.key(),
+            closed_at: campaign.survey_closed_at,
+        });
+
+        Ok(())
+    }
+
+    pub fn start_prorata_refunds(ctx: Context<StartProrataRefunds>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Refunds must be open: failed campaign past its deadline, or frozen by a takedown
+        require!(
+            campaign.is_frozen
+                || (campaign.has_ended(clock.unix_timestamp) && !campaign.is_successful),
+            CrowdfundingError::CampaignStillActive
+        );
+        require!(
+            campaign.prorata_denominator == 0,
+            CrowdfundingError::ProrataRefundsAlreadyStarted
+        );
+
+        let outstanding = campaign.outstanding_contributions();
+        let vault_balance = ctx.accounts.campaign_vault.amount
+            .saturating_sub(campaign.deferred_refunds);
+        require!(vault_balance < outstanding, CrowdfundingError::VaultFullyCollateralized);
+
+        // Fix the payout ratio once so every remaining backer gets the same share
+        campaign.prorata_numerator = vault_balance;
+        campaign.prorata_denominator = outstanding;
+
+        emit!(ProrataRefundsStarted {
+            campaign: campaign.key(),
+            vault_balance,
+            outstanding,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_template(
+        ctx: Context<CreateTemplate>,
+        name: String,
+        params: CampaignParams,
+    ) -> Result<()> {
+        let template = &mut ctx.accounts.template;
+
+        require!(name.len() <= CampaignTemplate::MAX_NAME_LEN, CrowdfundingError::TemplateNameTooLong);
+        params.validate()?;
+
+        template.authority = ctx.accounts.authority.key();
+        template.name = name;
+        template.params = params;
+        template.campaigns_created = 0;
+
+        emit!(TemplateCreated {
+            template: template.key(),
+            authority: template.authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_campaign_from_template(
+        ctx: Context<InitializeCampaignFromTemplate>,
+        title: String,
+        description: String,
+    ) -> Result<()> {
+        let template = &mut ctx.accounts.template;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            template.authority == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedTemplateUse
+        );
+
+        setup_campaign(
+            &mut ctx.accounts.campaign,
+            &ctx.accounts.config,
+            ctx.accounts.creator.key(),
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_minimum,
+            &ctx.accounts.creator_profile,
+            ctx.accounts.mint_metadata.as_deref(),
+            title,
+            description,
+            &template.params,
+            clock.unix_timestamp,
+        )?;
+
+        template.campaigns_created += 1;
+
+        Ok(())
+    }
+
+    pub fn create_series(ctx: Context<CreateSeries>, name: String) -> Result<()> {
+        let series = &mut ctx.accounts.series;
+
+        require!(name.len() <= Series::MAX_NAME_LEN, CrowdfundingError::SeriesNameTooLong);
+
+        series.creator = ctx.accounts.creator.key();
+        series.name = name;
+        series.season = 0;
+        series.cumulative_raised = 0;
+        series.current_campaign = Pubkey::default();
+
+        emit!(SeriesCreated {
+            series: series.key(),
+            creator: series.creator,
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_next_in_series(
+        ctx: Context<InitializeNextInSeries>,
+        title: String,
+        description: String,
+        params: CampaignParams,
+    ) -> Result<()> {
+        let series = &mut ctx.accounts.series;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            series.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // The previous season must be settled before the next one opens. An archived season
+        // was settled by definition, and its summary keeps the figures the series needs
+        if series.current_campaign != Pubkey::default() {
+            let (previous_successful, previous_raised) = match (
+                ctx.accounts.previous_campaign.as_ref(),
+                ctx.accounts.previous_summary.as_ref(),
+            ) {
+                (Some(previous), _) => {
+                    require!(
+                        previous.key() == series.current_campaign,
+                        CrowdfundingError::PreviousSeasonRequired
+                    );
+                    require!(
+                        previous.is_settled(clock.unix_timestamp),
+                        CrowdfundingError::PreviousSeasonNotSettled
+                    );
+                    (previous.is_successful, previous.current_amount)
+                }
+                (None, Some(summary)) => {
+                    require!(
+                        summary.campaign == series.current_campaign,
+                        CrowdfundingError::PreviousSeasonRequired
+                    );
+                    (summary.is_successful, summary.raised_amount)
+                }
+                (None, None) => return err!(CrowdfundingError::PreviousSeasonRequired),
+            };
+
+            if previous_successful {
+                series.cumulative_raised = series.cumulative_raised
+                    .checked_add(previous_raised)
+                    .ok_or(CrowdfundingError::AmountOverflow)?;
+            }
+        }
+
+        setup_campaign(
+            &mut ctx.accounts.campaign,
+            &ctx.accounts.config,
+            ctx.accounts.creator.key(),
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_minimum,
+            &ctx.accounts.creator_profile,
+            ctx.accounts.mint_metadata.as_deref(),
+            title,
+            description,
+            &params,
+            clock.unix_timestamp,
+        )?;
+
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.series = series.key();
+        series.season += 1;
+        series.current_campaign = campaign.key();
+
+        emit!(SeasonStarted {
+            series: series.key(),
+            campaign: campaign.key(),
+            season: series.season,
+            cumulative_raised: series.cumulative_raised,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_fee_schedule(
+        ctx: Context<UpdateConfig>,
+        fee_bps: u16,
+        treasury: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(fee_bps <= GlobalConfig::MAX_FEE_BPS, CrowdfundingError::InvalidFeeBps);
+
+        // Increases wait out the timelock so creators can react; decreases apply immediately.
+        // A new call replaces any increase that is still pending.
+        let now = Clock::get()?.unix_timestamp;
+        let current_fee_bps = config.effective_fee_bps(now);
+        let effective_at = if fee_bps > current_fee_bps {
+            config.fee_bps = current_fee_bps;
+            config.pending_fee_bps = fee_bps;
+            config.pending_fee_effective_at = now + config.timelock_seconds;
+            config.pending_fee_effective_at
+        } else {
+            config.fee_bps = fee_bps;
+            config.pending_fee_effective_at = 0;
+            now
+        };
+        config.treasury = treasury;
+
+        emit!(FeeScheduleUpdated { fee_bps, treasury, effective_at });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetFeeSchedule,
+            treasury,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_campaign_fee_override(
+        ctx: Context<SetCampaignFeeOverride>,
+        fee_bps: u16,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        // Backers must know the fee terms before they contribute
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(fee_bps <= GlobalConfig::MAX_FEE_BPS, CrowdfundingError::InvalidFeeBps);
+
+        campaign.fee_override_bps = Some(fee_bps);
+
+        emit!(CampaignFeeOverrideSet {
+            campaign: campaign.key(),
+            fee_bps,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetCampaignFeeOverride,
+            ctx.accounts.campaign.key(),
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn update_campaign_parameters(
+        ctx: Context<UpdateCampaign>,
+        target_amount: u64,
+        end_time: i64,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // Economic terms are frozen once the first backer has committed to them
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(target_amount > 0, CrowdfundingError::InvalidTargetAmount);
+        require!(
+            target_amount >= campaign.min_target_amount,
+            CrowdfundingError::TargetBelowMintMinimum
+        );
+        require!(
+            end_time > clock.unix_timestamp
+                && end_time <= campaign.start_time + Campaign::MAX_DURATION_SECONDS,
+            CrowdfundingError::InvalidDuration
+        );
+
+        campaign.target_amount = target_amount;
+        campaign.set_end_time(end_time);
+
+        emit!(CampaignParametersUpdated {
+            campaign: campaign.key(),
+            target_amount,
+            end_time,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_refund_address(ctx: Context<SetRefundAddress>, refund_address: Pubkey) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            contribution.refund_authority() == ctx.accounts.contributor.key(),
+            CrowdfundingError::UnauthorizedRefund
+        );
+
+        // Only while the campaign is active, so it can't race a pending refund
+        require!(!campaign.has_ended(clock.unix_timestamp), CrowdfundingError::CampaignEnded);
+
+        contribution.refund_address = refund_address;
+
+        emit!(RefundAddressSet {
+            campaign: campaign.key(),
+            contributor: contribution.contributor,
+            refund_address,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_inactivity_timeout(ctx: Context<UpdateCampaign>, timeout_seconds: u64) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // Backers must know the rule before they contribute
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        campaign.inactivity_timeout = timeout_seconds;
+
+        emit!(InactivityTimeoutSet {
+            campaign: campaign.key(),
+            timeout_seconds,
+        });
+
+        Ok(())
+    }
+
+    pub fn reclaim_stalled_funds(ctx: Context<ReclaimStalledFunds>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        require!(ctx.accounts.contribution.amount > 0, CrowdfundingError::NotABacker);
+        require!(campaign.inactivity_timeout > 0, CrowdfundingError::InactivityTimeoutNotSet);
+        require!(campaign.is_successful, CrowdfundingError::CampaignNotSuccessful);
+        require!(!campaign.is_withdrawn, CrowdfundingError::AlreadyWithdrawn);
+        require!(!campaign.is_stalled, CrowdfundingError::CampaignStalled);
+
+        let deadline = campaign.succeeded_at
+            .checked_add(campaign.inactivity_timeout as i64)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        require!(clock.unix_timestamp >= deadline, CrowdfundingError::CreatorStillActive);
+
+        // Re-open refunds, sharing whatever is left in the vault pro-rata
+        campaign.is_stalled = true;
+        campaign.prorata_numerator = ctx.accounts.campaign_vault.amount;
+        campaign.prorata_denominator = campaign.outstanding_contributions();
+
+        emit!(CampaignStalled {
+            campaign: campaign.key(),
+            triggered_by: ctx.accounts.contributor.key(),
+            vault_balance: campaign.prorata_numerator,
+            decimals: campaign.decimals,
+        });
+        log!(
+            "stall",
+            campaign = campaign.key(),
+            actor = ctx.accounts.contributor.key(),
+            amount = campaign.prorata_numerator,
+            state = "stalled",
+        );
+
+        Ok(())
+    }
+
+    pub fn set_withdrawal_approvers(
+        ctx: Context<UpdateCampaign>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        // An empty list with a zero threshold turns approvals off
+        require!(
+            approvers.len() <= Campaign::MAX_APPROVERS && threshold as usize <= approvers.len(),
+            CrowdfundingError::InvalidApproverSet
+        );
+        require!(
+            approvers.is_empty() || threshold > 0,
+            CrowdfundingError::InvalidApproverSet
+        );
+        for (i, approver) in approvers.iter().enumerate() {
+            require!(
+                !approvers[..i].contains(approver),
+                CrowdfundingError::InvalidApproverSet
+            );
+        }
+
+        campaign.approvers = approvers;
+        campaign.approval_threshold = threshold;
+        // Bits in an existing ApprovalSet refer to the old list; a new epoch voids them
+        campaign.approvers_epoch = campaign.approvers_epoch.wrapping_add(1);
+
+        emit!(WithdrawalApproversSet {
+            campaign: campaign.key(),
+            approvers: campaign.approvers.clone(),
+            threshold,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let approval_set = &mut ctx.accounts.approval_set;
+
+        let index = campaign.approvers
+            .iter()
+            .position(|approver| approver == &ctx.accounts.approver.key())
+            .ok_or(CrowdfundingError::NotAnApprover)?;
+
+        // Approving only makes sense once there is a successful raise to release
+        require!(campaign.is_successful, CrowdfundingError::CampaignNotSuccessful);
+
+        if approval_set.epoch != campaign.approvers_epoch {
+            approval_set.approvals_bitmap = 0;
+            approval_set.epoch = campaign.approvers_epoch;
+        }
+
+        approval_set.campaign = campaign.key();
+        approval_set.approvals_bitmap |= 1 << index;
+
+        emit!(WithdrawalApproved {
+            campaign: campaign.key(),
+            approver: ctx.accounts.approver.key(),
+            approvals: approval_set.approvals(),
+        });
+
+        Ok(())
+    }
+
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        let campaign = &ctx.accounts.withdraw.campaign;
+
+        require!(campaign.approval_threshold > 0, CrowdfundingError::InvalidApproverSet);
+        require!(
+            ctx.accounts.approval_set.epoch == campaign.approvers_epoch,
+            CrowdfundingError::StaleApprovals
+        );
+        require!(
+            ctx.accounts.approval_set.approvals() >= campaign.approval_threshold as u32,
+            CrowdfundingError::InsufficientApprovals
+        );
+
+        process_withdrawal(&mut ctx.accounts.withdraw, ctx.bumps.withdraw.campaign_vault)
+    }
+
+    pub fn set_contribution_cap(
+        ctx: Context<UpdateCampaign>,
+        max_per_contributor: u64,
+        reputation_scaled: bool,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        campaign.max_per_contributor = max_per_contributor;
+        campaign.reputation_scaled_cap = reputation_scaled;
+
+        emit!(ContributionCapSet {
+            campaign: campaign.key(),
+            max_per_contributor,
+            reputation_scaled,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_reputation_tier(
+        ctx: Context<SetReputationTier>,
+        contributor: Pubkey,
+        tier: u8,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(
+            tier <= ContributorProfile::MAX_REPUTATION_TIER,
+            CrowdfundingError::InvalidReputationTier
+        );
+
+        profile.contributor = contributor;
+        profile.reputation_tier = tier;
+
+        emit!(ReputationTierSet { contributor, tier });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetReputationTier,
+            contributor,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn finalize_campaign(ctx: Context<FinalizeCampaign>) -> Result<types::SettlementSummary> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        require!(
+            campaign.is_settled(clock.unix_timestamp),
+            CrowdfundingError::CampaignNotSettled
+        );
+
+        // Repeated calls return the same summary so CPI consumers can read it at any time
+        if campaign.finalized_at == 0 {
+            campaign.finalized_at = clock.unix_timestamp;
+
+            let profile = &mut ctx.accounts.creator_profile;
+            profile.creator = campaign.creator;
+            if campaign.settlement_outcome() != types::SettlementOutcome::Succeeded {
+                profile.campaigns_failed += 1;
+            }
+
+            emit!(CampaignFinalized {
+                campaign: campaign.key(),
+                is_successful: campaign.is_successful && !campaign.is_stalled,
+                raised_amount: campaign.current_amount,
+                decimals: campaign.decimals,
+            });
+            log!(
+                "finalize",
+                campaign = campaign.key(),
+                amount = campaign.current_amount,
+                state = "finalized",
+            );
+        }
+
+        // Refunds keep landing after finalization, so every call refreshes the report
+        let report = &mut ctx.accounts.settlement_report;
+        report.campaign = campaign.key();
+        report.outcome = campaign.settlement_outcome();
+        report.target_amount = campaign.target_amount;
+        report.raised_amount = campaign.current_amount;
+        report.fee_collected = campaign.fee_collected;
+        report.fee_waived = campaign.fee_waived;
+        report.matched_amount = campaign.matched_amount;
+        report.refunded_amount = campaign.total_refunded;
+        report.contributors_count = campaign.contributors_count;
+        report.start_time = campaign.start_time;
+        report.end_time = campaign.end_time;
+        report.succeeded_at = campaign.succeeded_at;
+        report.finalized_at = campaign.finalized_at;
+        report.updated_at = clock.unix_timestamp;
+
+        Ok(types::SettlementSummary {
+            version: types::SETTLEMENT_SUMMARY_VERSION,
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            outcome: report.outcome,
+            target_amount: campaign.target_amount,
+            raised_amount: campaign.current_amount,
+            refunded_amount: campaign.total_refunded,
+            contributors_count: campaign.contributors_count,
+            end_time: campaign.end_time,
+            finalized_at: campaign.finalized_at,
+            fee_collected: campaign.fee_collected,
+            matched_amount: campaign.matched_amount,
+        })
+    }
+
+    pub fn set_campaign_category(ctx: Context<UpdateCampaign>, category: u8) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        // Verification vouches for the campaign as reviewed, including its category
+        campaign.category = category;
+        campaign.is_verified = false;
+
+        emit!(CampaignCategorySet {
+            campaign: campaign.key(),
+            category,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_round(
+        ctx: Context<CreateRound>,
+        name: String,
+        start_time: i64,
+        end_time: i64,
+        eligible_category: u8,
+        max_match_per_campaign: u64,
+    ) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+
+        require!(name.len() <= Round::MAX_NAME_LEN, CrowdfundingError::RoundNameTooLong);
+        require!(
+            start_time < end_time && end_time > Clock::get()?.unix_timestamp,
+            CrowdfundingError::InvalidRoundWindow
+        );
+
+        round.authority = ctx.accounts.authority.key();
+        round.name = name;
+        round.mint = ctx.accounts.mint.key();
+        round.start_time = start_time;
+        round.end_time = end_time;
+        round.eligible_category = eligible_category;
+        round.max_match_per_campaign = max_match_per_campaign;
+        round.matching_pool = 0;
+        round.total_weight = 0;
+        round.enrolled_count = 0;
+        round.settled_count = 0;
+        round.finalized_at = 0;
+        round.attestor = Pubkey::default();
+        round.default_weight_bps = Round::FULL_WEIGHT_BPS;
+
+        emit!(RoundCreated {
+            round: round.key(),
+            authority: round.authority,
+            start_time,
+            end_time,
+            eligible_category,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_round_weight_source(
+        ctx: Context<UpdateRound>,
+        attestor: Pubkey,
+        default_weight_bps: u16,
+    ) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+
+        // Check permissions
+        require!(
+            round.authority == ctx.accounts.authority.key(),
+            CrowdfundingError::UnauthorizedRoundAuthority
+        );
+
+        // Weights must be fixed before any contribution is scored with them
+        require!(
+            Clock::get()?.unix_timestamp < round.start_time,
+            CrowdfundingError::RoundAlreadyStarted
+        );
+        require!(
+            default_weight_bps <= Round::FULL_WEIGHT_BPS,
+            CrowdfundingError::InvalidTrustWeight
+        );
+
+        round.attestor = attestor;
+        round.default_weight_bps = default_weight_bps;
+
+        emit!(RoundWeightSourceSet {
+            round: round.key(),
+            attestor,
+            default_weight_bps,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_trust_weight(
+        ctx: Context<SetTrustWeight>,
+        contributor: Pubkey,
+        weight_bps: u16,
+    ) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+
+        require!(weight_bps <= Round::FULL_WEIGHT_BPS, CrowdfundingError::InvalidTrustWeight);
+
+        attestation.attestor = ctx.accounts.attestor.key();
+        attestation.contributor = contributor;
+        attestation.weight_bps = weight_bps;
+        attestation.updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(TrustWeightSet {
+            attestor: attestation.attestor,
+            contributor,
+            weight_bps,
+        });
+
+        Ok(())
+    }
+
+    pub fn fund_round(ctx: Context<FundRound>, amount: u64) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+
+        require!(amount > 0, CrowdfundingError::InvalidContributionAmount);
+        require!(
+            Clock::get()?.unix_timestamp < round.end_time,
+            CrowdfundingError::RoundEnded
+        );
+
+        let vault_before = ctx.accounts.round_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            to: ctx.accounts.round_vault.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.round_vault, vault_before.checked_add(amount))?;
+
+        round.matching_pool = round.matching_pool
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+
+        emit!(RoundFunded {
+            round: round.key(),
+            funder: ctx.accounts.funder.key(),
+            amount,
+            matching_pool: round.matching_pool,
+        });
+
+        Ok(())
+    }
+
+    pub fn enroll_in_round(ctx: Context<EnrollInRound>) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        let campaign = &ctx.accounts.campaign;
+        let enrollment = &mut ctx.accounts.enrollment;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(clock.unix_timestamp < round.end_time, CrowdfundingError::RoundEnded);
+        require!(!campaign.has_ended(clock.unix_timestamp), CrowdfundingError::CampaignEnded);
+        require!(
+            round.eligible_category == 0 || round.eligible_category == campaign.category,
+            CrowdfundingError::CategoryNotEligible
+        );
+        require!(
+            ctx.accounts.campaign_vault.mint == round.mint,
+            CrowdfundingError::RoundMintMismatch
+        );
+
+        enrollment.round = round.key();
+        enrollment.campaign = campaign.key();
+        enrollment.raised_in_round = 0;
+        enrollment.sqrt_sum = 0;
+        enrollment.match_amount = 0;
+        enrollment.is_settled = false;
+
+        round.enrolled_count += 1;
+
+        emit!(CampaignEnrolled {
+            round: round.key(),
+            campaign: campaign.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn contribute_in_round(
+        ctx: Context<ContributeInRound>,
+        args: types::ContributeArgs,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let amount = args.amount;
+
+        require!(
+            ctx.accounts.round.is_active(clock.unix_timestamp),
+            CrowdfundingError::RoundNotActive
+        );
+
+        process_contribute(&mut ctx.accounts.contribute, args)?;
+
+        // Tag the contribution so the round can weigh it quadratically
+        let round = &mut ctx.accounts.round;
+        let enrollment = &mut ctx.accounts.enrollment;
+        let record = &mut ctx.accounts.round_contribution;
+
+        // Backers without an attestation from the round's weight source get the default weight;
+        // the PDA is always passed, so a low-scored backer can't fall back to it by omission
+        let trust_attestation = &ctx.accounts.trust_attestation;
+        let weight_bps = if round.attestor == Pubkey::default() {
+            Round::FULL_WEIGHT_BPS
+        } else if trust_attestation.data_is_empty() {
+            round.default_weight_bps
+        } else {
+            require!(
+                trust_attestation.owner == &crate::ID,
+                CrowdfundingError::InvalidTrustAttestation
+            );
+            let attestation =
+                TrustAttestation::try_deserialize(&mut &trust_attestation.try_borrow_data()?[..])?;
+            attestation.weight_bps
+        };
+
+        let previous_term = record.sqrt_term();
+        record.round = round.key();
+        record.campaign = enrollment.campaign;
+        record.contributor = ctx.accounts.contribute.contributor.key();
+        record.amount = record.amount
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        record.weight_bps = weight_bps;
+
+        let previous_weight = enrollment.weight();
+        enrollment.sqrt_sum = enrollment.sqrt_sum - previous_term + record.sqrt_term();
+        enrollment.raised_in_round = enrollment.raised_in_round
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        round.total_weight = round.total_weight - previous_weight + enrollment.weight();
+
+        emit!(RoundContributionTagged {
+            round: round.key(),
+            campaign: enrollment.campaign,
+            contributor: record.contributor,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn finalize_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeRound<'info>>,
+    ) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            round.authority == ctx.accounts.authority.key(),
+            CrowdfundingError::UnauthorizedRoundAuthority
+        );
+
+        require!(clock.unix_timestamp >= round.end_time, CrowdfundingError::RoundNotEnded);
+
+        // Enrollments are settled in batches passed as writable (enrollment, campaign, campaign vault)
+        require!(
+            ctx.remaining_accounts.len() % 3 == 0,
+            CrowdfundingError::InvalidRoundBatch
+        );
+
+        if round.finalized_at == 0 {
+            round.finalized_at = clock.unix_timestamp;
+        }
+
+        let round_key = round.key();
+        let seeds = &[
+            b"round_vault",
+            round_key.as_ref(),
+            &[ctx.bumps.round_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        for batch in ctx.remaining_accounts.chunks(3) {
+            let mut enrollment = Account::<RoundEnrollment>::try_from(&batch[0])?;
+            let mut campaign = Account::<Campaign>::try_from(&batch[1])?;
+            let campaign_vault = &batch[2];
+
+            require!(
+                enrollment.round == round_key
+                    && enrollment.campaign == campaign.key()
+                    && batch[1].is_writable,
+                CrowdfundingError::InvalidRoundBatch
+            );
+            let (vault_address, _) = Pubkey::find_program_address(
+                &[b"vault", campaign.key().as_ref()],
+                ctx.program_id,
+            );
+            require!(campaign_vault.key() == vault_address, CrowdfundingError::InvalidRoundBatch);
+
+            if enrollment.is_settled {
+                continue;
+            }
+
+            // Only campaigns that can still pay out receive a match; the rest stays in the pool
+            let eligible = campaign.is_successful
+                && !campaign.is_withdrawn
+                && !campaign.is_frozen
+                && !campaign.is_stalled;
+            let match_amount = if eligible {
+                round.match_for(enrollment.weight())
+            } else {
+                0
+            };
+
+            if match_amount > 0 {
+                let vault_before = ctx.accounts.round_vault.amount;
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.round_vault.to_account_info(),
+                    to: campaign_vault.clone(),
+                    authority: ctx.accounts.round_vault.to_account_info(),
+                };
+
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                token::transfer(cpi_ctx, match_amount)?;
+                expect_balance_after_cpi(
+                    &mut ctx.accounts.round_vault,
+                    vault_before.checked_sub(match_amount),
+                )?;
+            }
+
+            enrollment.match_amount = match_amount;
+            enrollment.is_settled = true;
+            enrollment.exit(ctx.program_id)?;
+
+            campaign.matched_amount = campaign.matched_amount
+                .checked_add(match_amount)
+                .ok_or(CrowdfundingError::AmountOverflow)?;
+            campaign.exit(ctx.program_id)?;
+            round.settled_count += 1;
+
+            emit!(MatchDisbursed {
+                round: round_key,
+                campaign: campaign.key(),
+                amount: match_amount,
+                decimals: campaign.decimals,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn reclaim_round_remainder(ctx: Context<ReclaimRoundRemainder>) -> Result<()> {
+        let round = &ctx.accounts.round;
+
+        // Check permissions
+        require!(
+            round.authority == ctx.accounts.authority.key(),
+            CrowdfundingError::UnauthorizedRoundAuthority
+        );
+
+        require!(
+            round.finalized_at > 0 && round.settled_count == round.enrolled_count,
+            CrowdfundingError::RoundNotSettled
+        );
+
+        let remainder = ctx.accounts.round_vault.amount;
+        require!(remainder > 0, CrowdfundingError::NoFundsToWithdraw);
+
+        let round_key = round.key();
+        let seeds = &[
+            b"round_vault",
+            round_key.as_ref(),
+            &[ctx.bumps.round_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.round_vault.to_account_info(),
+            to: ctx.accounts.authority_token_account.to_account_info(),
+            authority: ctx.accounts.round_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, remainder)?;
+        expect_balance_after_cpi(&mut ctx.accounts.round_vault, Some(0))?;
+
+        emit!(RoundRemainderReclaimed {
+            round: round_key,
+            amount: remainder,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_platform_mint(
+        ctx: Context<UpdateConfig>,
+        platform_mint: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        config.platform_mint = platform_mint;
+
+        emit!(PlatformMintSet { platform_mint });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetPlatformMint,
+            platform_mint,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn stake_for_boost(ctx: Context<StakeForBoost>, amount: u64) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let boost = &mut ctx.accounts.boost;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(amount > 0, CrowdfundingError::InvalidStakeAmount);
+        require!(!campaign.has_ended(clock.unix_timestamp), CrowdfundingError::CampaignEnded);
+
+        let vault_before = ctx.accounts.boost_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.creator_token_account.to_account_info(),
+            to: ctx.accounts.boost_vault.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.boost_vault, vault_before.checked_add(amount))?;
+
+        if boost.campaign == Pubkey::default() {
+            boost.campaign = campaign.key();
+            boost.staked_at = clock.unix_timestamp;
+        }
+        boost.amount = boost.amount
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+
+        // Indexers rank featured campaigns by this weight
+        campaign.featured_weight = boost.amount;
+
+        emit!(BoostStaked {
+            campaign: campaign.key(),
+            amount,
+            featured_weight: campaign.featured_weight,
+        });
+
+        Ok(())
+    }
+
+    pub fn unstake_boost(ctx: Context<UnstakeBoost>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // The stake stays locked until the campaign has paid out or opened refunds
+        require!(
+            campaign.is_settled(clock.unix_timestamp),
+            CrowdfundingError::CampaignNotSettled
+        );
+
+        let amount = ctx.accounts.boost_vault.amount;
+        let campaign_key = campaign.key();
+        let seeds = &[
+            b"boost_vault",
+            campaign_key.as_ref(),
+            &[ctx.bumps.boost_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        if amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.boost_vault.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.boost_vault.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, amount)?;
+            expect_balance_after_cpi(&mut ctx.accounts.boost_vault, Some(0))?;
+        }
+
+        // Close the empty stake vault, returning its rent to the creator
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.boost_vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.boost_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::close_account(cpi_ctx)?;
+
+        campaign.featured_weight = 0;
+
+        emit!(BoostUnstaked {
+            campaign: campaign_key,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_points_config(
+        ctx: Context<SetPointsConfig>,
+        accrual_bps: u16,
+        points_per_discount_bps: u64,
+        max_fee_discount_bps: u16,
+        badge_cost: u64,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let points_config = &mut ctx.accounts.points_config;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(
+            accrual_bps <= 10_000 && max_fee_discount_bps <= GlobalConfig::MAX_FEE_BPS,
+            CrowdfundingError::InvalidPointsConfig
+        );
+
+        points_config.accrual_bps = accrual_bps;
+        points_config.points_per_discount_bps = points_per_discount_bps;
+        points_config.max_fee_discount_bps = max_fee_discount_bps;
+        points_config.badge_cost = badge_cost;
+
+        emit!(PointsConfigUpdated {
+            accrual_bps,
+            points_per_discount_bps,
+            max_fee_discount_bps,
+            badge_cost,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetPointsConfig,
+            ctx.accounts.points_config.key(),
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn init_contributor_profile(ctx: Context<InitContributorProfile>) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+
+        profile.contributor = ctx.accounts.contributor.key();
+        profile.reputation_tier = 0;
+        profile.points = 0;
+
+        Ok(())
+    }
+
+    pub fn redeem_points_for_fee_discount(
+        ctx: Context<RedeemPointsForFeeDiscount>,
+        discount_bps: u16,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let profile = &mut ctx.accounts.contributor_profile;
+        let points_config = &ctx.accounts.points_config;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.is_withdrawn, CrowdfundingError::CampaignAlreadyWithdrawn);
+        require!(
+            discount_bps > 0
+                && campaign.fee_discount_bps.saturating_add(discount_bps)
+                    <= points_config.max_fee_discount_bps,
+            CrowdfundingError::InvalidFeeDiscount
+        );
+
+        let cost = points_config.points_per_discount_bps
+            .checked_mul(discount_bps as u64)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        require!(profile.points >= cost, CrowdfundingError::InsufficientPoints);
+
+        profile.points -= cost;
+        campaign.fee_discount_bps += discount_bps;
+
+        emit!(PointsRedeemed {
+            contributor: profile.contributor,
+            points: cost,
+            reward: PointsReward::FeeDiscount {
+                campaign: campaign.key(),
+                discount_bps,
+            },
+        });
+
+        Ok(())
+    }
+
+    pub fn redeem_points_for_badge(ctx: Context<RedeemPoints>, badge_id: u16) -> Result<()> {
+        let profile = &mut ctx.accounts.contributor_profile;
+        let cost = ctx.accounts.points_config.badge_cost;
+
+        require!(cost > 0, CrowdfundingError::InvalidPointsConfig);
+        require!(profile.points >= cost, CrowdfundingError::InsufficientPoints);
+
+        profile.points -= cost;
+
+        // The badge itself is minted off-chain (or by a companion program) from this event
+        emit!(PointsRedeemed {
+            contributor: profile.contributor,
+            points: cost,
+            reward: PointsReward::Badge { badge_id },
+        });
+
+        Ok(())
+    }
+
+    pub fn add_team_member(ctx: Context<AddTeamMember>, member: Pubkey, roles: u8) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let team_member = &mut ctx.accounts.team_member;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(
+            roles != 0 && roles & !TeamMember::ALL_ROLES == 0,
+            CrowdfundingError::InvalidTeamRoles
+        );
+
+        // Calling again for an existing member replaces their roles
+        team_member.campaign = campaign.key();
+        team_member.member = member;
+        team_member.roles = roles;
+        team_member.added_at = Clock::get()?.unix_timestamp;
+
+        emit!(TeamMemberUpdated {
+            campaign: campaign.key(),
+            member,
+            roles,
+        });
+
+        Ok(())
+    }
+
+    pub fn remove_team_member(ctx: Context<RemoveTeamMember>) -> Result<()> {
+        // Check permissions
+        require!(
+            ctx.accounts.campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        emit!(TeamMemberUpdated {
+            campaign: ctx.accounts.campaign.key(),
+            member: ctx.accounts.team_member.member,
+            roles: 0,
+        });
+
+        Ok(())
+    }
+
+    pub fn post_update(ctx: Context<TeamAction>, content_hash: [u8; 32]) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        // Check permissions
+        check_team_role(
+            campaign,
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.team_member,
+            TeamMember::CAN_POST_UPDATES,
+        )?;
+
+        // Update bodies live off-chain; the hash pins what was published
+        emit!(CampaignUpdatePosted {
+            campaign: campaign.key(),
+            author: ctx.accounts.signer.key(),
+            content_hash,
+        });
+
+        Ok(())
+    }
+
+    pub fn edit_campaign_description(ctx: Context<TeamAction>, description: String) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        check_team_role(
+            campaign,
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.team_member,
+            TeamMember::CAN_EDIT_METADATA,
+        )?;
+
+        require!(description.len() <= 500, CrowdfundingError::DescriptionTooLong);
+
+        campaign.description = description;
+
+        emit!(CampaignMetadataEdited {
+            campaign: campaign.key(),
+            editor: ctx.accounts.signer.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn acknowledge_backers(
+        ctx: Context<AcknowledgeBackers>,
+        page: u32,
+        entries: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let list = &mut ctx.accounts.acknowledgments;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // Pages fill in order so readers can walk them from page 0 until one is missing
+        if page > 0 {
+            let previous = ctx.accounts.previous_page.as_ref()
+                .ok_or(CrowdfundingError::PreviousPageNotFull)?;
+            require!(previous.is_full(), CrowdfundingError::PreviousPageNotFull);
+        }
+
+        require!(!entries.is_empty(), CrowdfundingError::InvalidAcknowledgmentBatch);
+        require!(
+            list.entries.len() + entries.len() <= AcknowledgmentsList::MAX_ENTRIES,
+            CrowdfundingError::AcknowledgmentPageFull
+        );
+
+        list.campaign = campaign.key();
+        list.page = page;
+        list.entries.extend_from_slice(&entries);
+
+        emit!(BackersAcknowledged {
+            campaign: campaign.key(),
+            page,
+            added: entries.len() as u32,
+            total_on_page: list.entries.len() as u32,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_localized_metadata(
+        ctx: Context<SetLocalizedMetadata>,
+        locale: String,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        let metadata = &mut ctx.accounts.localized_metadata;
+
+        // Check permissions
+        check_team_role(
+            &ctx.accounts.campaign,
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.team_member,
+            TeamMember::CAN_EDIT_METADATA,
+        )?;
+
+        require!(
+            !locale.is_empty() && locale.len() <= LocalizedMetadata::MAX_LOCALE_LEN,
+            CrowdfundingError::InvalidLocale
+        );
+
+        metadata.campaign = ctx.accounts.campaign.key();
+        metadata.locale = locale.clone();
+        metadata.uri_hash = uri_hash;
+        metadata.updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(LocalizedMetadataSet {
+            campaign: metadata.campaign,
+            locale,
+            uri_hash,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_holder_gate(
+        ctx: Context<UpdateCampaign>,
+        gate_mint: Pubkey,
+        gate_is_collection: bool,
+        priority_ends_at: i64,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(
+            priority_ends_at <= campaign.end_time,
+            CrowdfundingError::InvalidPriorityWindow
+        );
+
+        campaign.gate_mint = gate_mint;
+        campaign.gate_is_collection = gate_is_collection;
+        campaign.priority_ends_at = priority_ends_at;
+
+        emit!(HolderGateSet {
+            campaign: campaign.key(),
+            gate_mint,
+            gate_is_collection,
+            priority_ends_at,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_holder_only(ctx: Context<UpdateCampaign>, holder_only: bool) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(
+            !holder_only || campaign.gate_mint != Pubkey::default(),
+            CrowdfundingError::HolderGateNotSet
+        );
+
+        campaign.holder_only = holder_only;
+
+        emit!(HolderOnlySet {
+            campaign: campaign.key(),
+            holder_only,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_streak_bonus(ctx: Context<UpdateCampaign>, streak_bonus_bps: u16) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(streak_bonus_bps <= 10_000, CrowdfundingError::InvalidStreakBonus);
+
+        campaign.streak_bonus_bps = streak_bonus_bps;
+
+        emit!(StreakBonusSet {
+            campaign: campaign.key(),
+            streak_bonus_bps,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_anti_sniping(
+        ctx: Context<UpdateCampaign>,
+        window_seconds: u32,
+        extension_seconds: u32,
+        max_total_extension_seconds: u32,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(
+            window_seconds <= Campaign::MAX_SNIPE_WINDOW_SECONDS
+                && extension_seconds <= Campaign::MAX_SNIPE_WINDOW_SECONDS
+                && max_total_extension_seconds <= Campaign::MAX_TOTAL_EXTENSION_SECONDS,
+            CrowdfundingError::InvalidAntiSnipingRule
+        );
+
+        campaign.snipe_window_seconds = window_seconds;
+        campaign.snipe_extension_seconds = extension_seconds;
+        campaign.max_total_extension_seconds = max_total_extension_seconds;
+
+        emit!(AntiSnipingSet {
+            campaign: campaign.key(),
+            window_seconds,
+            extension_seconds,
+            max_total_extension_seconds,
+        });
+
+        Ok(())
+    }
+
+    pub fn follow_campaign(ctx: Context<FollowCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let follow = &mut ctx.accounts.follow;
+
+        follow.campaign = campaign.key();
+        follow.follower = ctx.accounts.follower.key();
+        follow.followed_at = Clock::get()?.unix_timestamp;
+
+        campaign.followers_count += 1;
+
+        emit!(CampaignFollowed {
+            campaign: campaign.key(),
+            follower: follow.follower,
+            followers_count: campaign.followers_count,
+        });
+
+        Ok(())
+    }
+
+    pub fn unfollow_campaign(ctx: Context<UnfollowCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        campaign.followers_count = campaign.followers_count.saturating_sub(1);
+
+        emit!(CampaignUnfollowed {
+            campaign: campaign.key(),
+            follower: ctx.accounts.follower.key(),
+            followers_count: campaign.followers_count,
+        });
+
+        Ok(())
+    }
+
+    pub fn contribute_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ContributeBatch<'info>>,
+        entries: Vec<BatchContribution>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let contributor = ctx.accounts.contributor.key();
+
+        // Each entry is backed by writable (campaign, contribution, campaign vault, sketch) accounts
+        require!(
+            !entries.is_empty()
+                && entries.len() <= BatchContribution::MAX_ENTRIES
+                && ctx.remaining_accounts.len() == entries.len() * 4,
+            CrowdfundingError::InvalidContributionBatch
+        );
+
+        // Batches always spend from the contributor's own token account
+        require!(
+            ctx.accounts.contributor_token_account.owner == contributor,
+            CrowdfundingError::TokenOwnerNotAllowlisted
+        );
+
+        for (entry, batch) in entries.iter().zip(ctx.remaining_accounts.chunks(4)) {
+            let (campaign_info, contribution_info, vault_info, sketch_info) =
+                (&batch[0], &batch[1], &batch[2], &batch[3]);
+            require!(
+                campaign_info.key() == entry.campaign
+                    && campaign_info.is_writable
+                    && contribution_info.is_writable
+                    && vault_info.is_writable
+                    && sketch_info.is_writable,
+                CrowdfundingError::InvalidContributionBatch
+            );
+
+            let mut campaign = Account::<Campaign>::try_from(campaign_info)?;
+
+            // Gated campaigns need a holder proof, which batches don't carry
+            require!(!campaign.is_gated(clock.unix_timestamp), CrowdfundingError::GateProofRequired);
+            require!(!campaign.requires_cooldown(entry.amount), CrowdfundingError::CooldownRequired);
+
+            let (vault_address, _) = Pubkey::find_program_address(
+                &[b"vault", entry.campaign.as_ref()],
+                ctx.program_id,
+            );
+            let (contribution_address, contribution_bump) = Pubkey::find_program_address(
+                &[b"contribution", entry.campaign.as_ref(), contributor.as_ref()],
+                ctx.program_id,
+            );
+            let (sketch_address, _) = Pubkey::find_program_address(
+                &[b"sketch", entry.campaign.as_ref()],
+                ctx.program_id,
+            );
+            require!(
+                vault_info.key() == vault_address
+                    && contribution_info.key() == contribution_address
+                    && sketch_info.key() == sketch_address,
+                CrowdfundingError::InvalidContributionBatch
+            );
+
+            // First contribution to this campaign: create the record, as init_if_needed would
+            let mut contribution = if contribution_info.data_is_empty() {
+                let seeds = &[
+                    b"contribution",
+                    entry.campaign.as_ref(),
+                    contributor.as_ref(),
+                    &[contribution_bump],
+                ];
+                let cpi_accounts = system_program::CreateAccount {
+                    from: ctx.accounts.contributor.to_account_info(),
+                    to: contribution_info.clone(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    cpi_accounts,
+                    &[&seeds[..]],
+                );
+                system_program::create_account(
+                    cpi_ctx,
+                    Rent::get()?.minimum_balance(Contribution::SIZE),
+                    Contribution::SIZE as u64,
+                    ctx.program_id,
+                )?;
+                Account::<Contribution>::try_from_unchecked(contribution_info)?
+            } else {
+                Account::<Contribution>::try_from(contribution_info)?
+            };
+
+            require!(
+                contribution.custodian == Pubkey::default(),
+                CrowdfundingError::CustodianMismatch
+            );
+            campaign.check_contributor_cap(contribution.amount, entry.amount, 1)?;
+
+            credit_contribution(
+                &mut campaign,
+                &mut contribution,
+                sketch_info,
+                contributor,
+                entry.amount,
+                clock.unix_timestamp,
+            )?;
+
+            let mut vault = Account::<TokenAccount>::try_from(vault_info)?;
+            check_not_frozen(&ctx.accounts.contributor_token_account, &vault)?;
+            check_vault_authorities(&vault)?;
+            let vault_before = vault.amount;
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.contributor_token_account.to_account_info(),
+                to: vault_info.clone(),
+                authority: ctx.accounts.contributor.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::transfer(cpi_ctx, entry.amount)?;
+            expect_balance_after_cpi(&mut vault, vault_before.checked_add(entry.amount))?;
+
+            campaign.exit(ctx.program_id)?;
+            contribution.exit(ctx.program_id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn repair_vault_authorities(
+        ctx: Context<RepairVaultAuthorities>,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let vault = &ctx.accounts.campaign_vault;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        let campaign_key = ctx.accounts.campaign.key();
+        let seeds = &[
+            b"vault",
+            campaign_key.as_ref(),
+            &[ctx.bumps.campaign_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let (cleared_delegate, cleared_close_authority) = unexpected_vault_authorities(vault);
+
+        if cleared_delegate {
+            let cpi_accounts = Revoke {
+                source: vault.to_account_info(),
+                authority: vault.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::revoke(cpi_ctx)?;
+        }
+
+        if cleared_close_authority {
+            let cpi_accounts = SetAuthority {
+                current_authority: vault.to_account_info(),
+                account_or_mint: vault.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::set_authority(cpi_ctx, AuthorityType::CloseAccount, None)?;
+        }
+
+        emit!(VaultAuthoritiesRepaired {
+            campaign: campaign_key,
+            cleared_delegate,
+            cleared_close_authority,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RepairVaultAuthorities,
+            campaign_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_revenue_share(
+        ctx: Context<SetRevenueShare>,
+        share_bps: u16,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let revenue_share = &mut ctx.accounts.revenue_share;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(share_bps <= 10_000, CrowdfundingError::InvalidRevenueShare);
+
+        // The reward mint is fixed once the revenue vault exists
+        if revenue_share.reward_mint == Pubkey::default() {
+            revenue_share.reward_mint = ctx.accounts.reward_mint.key();
+        }
+        revenue_share.share_bps = share_bps;
+
+        emit!(RevenueShareConfigured {
+            reward_mint: revenue_share.reward_mint,
+            share_bps,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetRevenueShare,
+            ctx.accounts.revenue_share.key(),
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn stake_platform_tokens(ctx: Context<StakePlatformTokens>, amount: u64) -> Result<()> {
+        let revenue_share = &mut ctx.accounts.revenue_share;
+        let position = &mut ctx.accounts.stake_position;
+
+        require!(amount > 0, CrowdfundingError::InvalidStakeAmount);
+
+        let vault_before = ctx.accounts.stake_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.staker_token_account.to_account_info(),
+            to: ctx.accounts.stake_vault.to_account_info(),
+            authority: ctx.accounts.staker.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.stake_vault, vault_before.checked_add(amount))?;
+
+        // Lock in rewards earned at the old stake before it changes
+        position.staker = ctx.accounts.staker.key();
+        position.settle(revenue_share.acc_revenue_per_share)?;
+        position.amount = position.amount
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        position.reset_debt(revenue_share.acc_revenue_per_share);
+        revenue_share.total_staked = revenue_share.total_staked
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+
+        emit!(PlatformTokensStaked {
+            staker: position.staker,
+            amount,
+            total_staked: revenue_share.total_staked,
+        });
+
+        Ok(())
+    }
+
+    pub fn unstake_platform_tokens(ctx: Context<UnstakePlatformTokens>, amount: u64) -> Result<()> {
+        let revenue_share = &mut ctx.accounts.revenue_share;
+        let position = &mut ctx.accounts.stake_position;
+
+        require!(
+            amount > 0 && amount <= position.amount,
+            CrowdfundingError::InvalidStakeAmount
+        );
+
+        position.settle(revenue_share.acc_revenue_per_share)?;
+        position.amount -= amount;
+        position.reset_debt(revenue_share.acc_revenue_per_share);
+        revenue_share.total_staked -= amount;
+
+        let seeds = &[
+            b"stake_vault".as_ref(),
+            &[ctx.bumps.stake_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let vault_before = ctx.accounts.stake_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.stake_vault.to_account_info(),
+            to: ctx.accounts.staker_token_account.to_account_info(),
+            authority: ctx.accounts.stake_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.stake_vault, vault_before.checked_sub(amount))?;
+
+        emit!(PlatformTokensUnstaked {
+            staker: position.staker,
+            amount,
+            total_staked: revenue_share.total_staked,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_revenue_share(ctx: Context<ClaimRevenueShare>) -> Result<()> {
+        let position = &mut ctx.accounts.stake_position;
+
+        position.settle(ctx.accounts.revenue_share.acc_revenue_per_share)?;
+        position.reset_debt(ctx.accounts.revenue_share.acc_revenue_per_share);
+
+        let amount = position.pending_revenue;
+        require!(amount > 0, CrowdfundingError::NothingToClaim);
+        position.pending_revenue = 0;
+
+        let seeds = &[
+            b"revenue_vault".as_ref(),
+            &[ctx.bumps.revenue_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let vault_before = ctx.accounts.revenue_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.revenue_vault.to_account_info(),
+            to: ctx.accounts.staker_token_account.to_account_info(),
+            authority: ctx.accounts.revenue_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.revenue_vault, vault_before.checked_sub(amount))?;
+
+        emit!(RevenueShareClaimed {
+            staker: position.staker,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_faq_hash(ctx: Context<TeamAction>, faq_hash: [u8; 32]) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        check_team_role(
+            campaign,
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.team_member,
+            TeamMember::CAN_EDIT_METADATA,
+        )?;
+
+        // Re-publishing the same document doesn't bump the version
+        if campaign.faq_hash == faq_hash {
+            return Ok(());
+        }
+
+        campaign.faq_hash = faq_hash;
+        campaign.faq_version = campaign.faq_version
+            .checked_add(1)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+
+        emit!(FaqUpdated {
+            campaign: campaign.key(),
+            editor: ctx.accounts.signer.key(),
+            faq_hash,
+            faq_version: campaign.faq_version,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_contribution_cooldown(
+        ctx: Context<UpdateCampaign>,
+        cooldown_threshold: u64,
+        cooldown_seconds: u32,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(
+            cooldown_seconds <= Campaign::MAX_COOLDOWN_SECONDS,
+            CrowdfundingError::InvalidCooldown
+        );
+
+        campaign.cooldown_threshold = cooldown_threshold;
+        campaign.cooldown_seconds = cooldown_seconds;
+
+        emit!(ContributionCooldownSet {
+            campaign: campaign.key(),
+            cooldown_threshold,
+            cooldown_seconds,
+        });
+
+        Ok(())
+    }
+
+    pub fn queue_contribution(ctx: Context<QueueContribution>, amount: u64) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let pending = &mut ctx.accounts.pending_contribution;
+        let clock = Clock::get()?;
+
+        require!(campaign.requires_cooldown(amount), CrowdfundingError::CooldownNotRequired);
+        require!(!campaign.pending_acceptance, CrowdfundingError::CampaignPendingAcceptance);
+        require!(campaign.has_started(clock.unix_timestamp), CrowdfundingError::CampaignNotStarted);
+        require!(!campaign.has_ended(clock.unix_timestamp), CrowdfundingError::CampaignEnded);
+        require!(!campaign.is_gated(clock.unix_timestamp), CrowdfundingError::GateProofRequired);
+
+        pending.campaign = campaign.key();
+        pending.contributor = ctx.accounts.contributor.key();
+        pending.amount = amount;
+        pending.queued_at = clock.unix_timestamp;
+        pending.release_at = clock.unix_timestamp + campaign.cooldown_seconds as i64;
+        campaign.pending_contributions += 1;
+
+        // Tokens are escrowed now so the creator sees committed funds, not an IOU
+        check_not_frozen(&ctx.accounts.contributor_token_account, &ctx.accounts.pending_vault)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.contributor_token_account.to_account_info(),
+            to: ctx.accounts.pending_vault.to_account_info(),
+            authority: ctx.accounts.contributor.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.pending_vault, Some(amount))?;
+
+        emit!(ContributionQueued {
+            campaign: pending.campaign,
+            contributor: pending.contributor,
+            amount,
+            release_at: pending.release_at,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn execute_pending_contribution(ctx: Context<ExecutePendingContribution>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+        let amount = ctx.accounts.pending_contribution.amount;
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= ctx.accounts.pending_contribution.release_at,
+            CrowdfundingError::CooldownNotElapsed
+        );
+        require!(
+            contribution.custodian == Pubkey::default(),
+            CrowdfundingError::CustodianMismatch
+        );
+
+        campaign.check_contributor_cap(contribution.amount, amount, 1)?;
+
+        credit_contribution(
+            campaign,
+            contribution,
+            &ctx.accounts.contributor_sketch,
+            ctx.accounts.contributor.key(),
+            amount,
+            clock.unix_timestamp,
+        )?;
+        campaign.pending_contributions -= 1;
+
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        release_pending_escrow(
+            &ctx.accounts.pending_contribution,
+            &ctx.accounts.pending_vault,
+            ctx.accounts.campaign_vault.to_account_info(),
+            ctx.accounts.contributor.to_account_info(),
+            &ctx.accounts.token_program,
+            ctx.bumps.pending_vault,
+        )?;
+        expect_balance_after_cpi(&mut ctx.accounts.campaign_vault, vault_before.checked_add(amount))?;
+
+        emit!(PendingContributionResolved {
+            campaign: campaign.key(),
+            contributor: ctx.accounts.contributor.key(),
+            amount,
+            credited: true,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_pending_contribution(ctx: Context<ResolvePendingContribution>) -> Result<()> {
+        // Check permissions
+        require!(
+            ctx.accounts.signer.key() == ctx.accounts.pending_contribution.contributor,
+            CrowdfundingError::UnauthorizedRefund
+        );
+
+        return_pending_contribution(ctx)
+    }
+
+    pub fn reject_pending_contribution(ctx: Context<ResolvePendingContribution>) -> Result<()> {
+        // Check permissions
+        require!(
+            ctx.accounts.signer.key() == ctx.accounts.campaign.creator,
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        return_pending_contribution(ctx)
+    }
+
+    pub fn set_content_key_hash(ctx: Context<UpdateCampaign>, content_key_hash: [u8; 32]) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        campaign.content_key_hash = content_key_hash;
+
+        emit!(ContentKeyHashSet {
+            campaign: campaign.key(),
+            content_key_hash,
+        });
+
+        Ok(())
+    }
+
+    pub fn prove_backer(ctx: Context<ProveBacker>, challenge: [u8; 32]) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let contribution = &ctx.accounts.contribution;
+
+        require!(contribution.amount > 0, CrowdfundingError::NotABacker);
+        require!(
+            campaign.content_key_hash != [0; 32],
+            CrowdfundingError::ContentKeyNotSet
+        );
+
+        // The frontend's challenge ties this proof to one session, so a copied transaction
+        // can't unlock content for someone else
+        emit!(BackerProven {
+            campaign: campaign.key(),
+            backer: ctx.accounts.backer.key(),
+            amount: contribution.amount,
+            decimals: campaign.decimals,
+            content_key_hash: campaign.content_key_hash,
+            challenge,
+            proven_at: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_timelock(
+        ctx: Context<UpdateConfig>,
+        timelock_seconds: i64,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        // A compromised key must not be able to shorten the delay it would have to wait out
+        require!(
+            timelock_seconds >= config.timelock_seconds
+                && timelock_seconds <= GlobalConfig::MAX_TIMELOCK_SECONDS,
+            CrowdfundingError::InvalidTimelock
+        );
+
+        config.timelock_seconds = timelock_seconds;
+        let config_key = config.key();
+
+        emit!(TimelockUpdated { timelock_seconds });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetTimelock,
+            config_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn propose_admin(
+        ctx: Context<UpdateConfig>,
+        new_admin: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(new_admin != Pubkey::default(), CrowdfundingError::InvalidAdmin);
+
+        config.pending_admin = new_admin;
+        config.admin_transfer_available_at = Clock::get()?.unix_timestamp + config.timelock_seconds;
+
+        emit!(AdminTransferProposed {
+            current_admin: config.admin,
+            pending_admin: new_admin,
+            available_at: config.admin_transfer_available_at,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::ProposeAdmin,
+            new_admin,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<UpdateConfig>, memo_hash: [u8; 32]) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions: the guardian can veto a rotation it didn't expect
+        require!(
+            config.is_authority(&ctx.accounts.admin.key()),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        let pending_admin = config.pending_admin;
+        require!(pending_admin != Pubkey::default(), CrowdfundingError::NoPendingAdmin);
+
+        config.pending_admin = Pubkey::default();
+        config.admin_transfer_available_at = 0;
+
+        emit!(AdminTransferCancelled { pending_admin });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::CancelAdminProposal,
+            pending_admin,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>, memo_hash: [u8; 32]) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        require!(config.pending_admin != Pubkey::default(), CrowdfundingError::NoPendingAdmin);
+
+        // Check permissions
+        require!(
+            config.pending_admin == ctx.accounts.new_admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        require!(
+            Clock::get()?.unix_timestamp >= config.admin_transfer_available_at,
+            CrowdfundingError::TimelockNotElapsed
+        );
+
+        let previous_admin = config.admin;
+        config.admin = config.pending_admin;
+        config.pending_admin = Pubkey::default();
+        config.admin_transfer_available_at = 0;
+
+        emit!(AdminTransferred {
+            previous_admin,
+            new_admin: config.admin,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.new_admin.key(),
+            AdminActionKind::AcceptAdmin,
+            previous_admin,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_mint_minimum_target(
+        ctx: Context<SetMintMinimumTarget>,
+        mint: Pubkey,
+        min_target_amount: u64,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let entry = &mut ctx.accounts.mint_minimum;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        entry.mint = mint;
+        entry.min_target_amount = min_target_amount;
+
+        emit!(MintMinimumTargetSet {
+            mint,
+            min_target_amount,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetMintMinimumTarget,
+            mint,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn compute_health(ctx: Context<ComputeHealth>) -> Result<u16> {
+        let campaign = &ctx.accounts.campaign;
+        let analytics = &mut ctx.accounts.analytics;
+
+        let reputation_tier = ctx.accounts.creator_profile
+            .as_ref()
+            .map(|profile| profile.reputation_tier);
+
+        analytics.campaign = campaign.key();
+        let score = analytics.record_health(campaign, reputation_tier, Clock::get()?.unix_timestamp);
+
+        // Returned as well as stored, so rankers can simulate the instruction instead of
+        // fetching the account afterwards
+        Ok(score)
+    }
+
+    pub fn set_campaign_verified(
+        ctx: Context<SetCampaignVerified>,
+        verified: bool,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        campaign.is_verified = verified;
+
+        emit!(CampaignVerificationSet {
+            campaign: campaign.key(),
+            verified,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetCampaignVerified,
+            ctx.accounts.campaign.key(),
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn set_charity_fee_waiver(
+        ctx: Context<UpdateConfig>,
+        enabled: bool,
+        charity_category: u8,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        // Category 0 means "uncategorized", which every campaign starts as
+        require!(!enabled || charity_category != 0, CrowdfundingError::InvalidCharityCategory);
+
+        config.charity_fee_waiver = enabled;
+        config.charity_category = charity_category;
+        let config_key = config.key();
+
+        emit!(CharityFeeWaiverSet {
+            enabled,
+            charity_category,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetCharityFeeWaiver,
+            config_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates the contribution record on its own, so the funding transaction never has to.
+    pub fn open_contribution(ctx: Context<OpenContribution>) -> Result<()> {
+        let contribution = &mut ctx.accounts.contribution;
+        contribution.contributor = ctx.accounts.contributor.key();
+        contribution.campaign = ctx.accounts.campaign.key();
+
+        Ok(())
+    }
+
+    /// Retry-safe contribute for congested clusters and durable-nonce transactions.
+    ///
+    /// The record must already exist (see `open_contribution`) and `expected_seq` must match its
+    /// current `seq`, so a resubmitted copy of a transaction that already landed fails instead
+    /// of crediting twice. `valid_until` bounds how late a pre-signed transaction may execute.
+    pub fn contribute_sequenced(
+        ctx: Context<ContributeSequenced>,
+        amount: u64,
+        expected_seq: u64,
+        valid_until: i64,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+        let clock = Clock::get()?;
+
+        require!(clock.unix_timestamp <= valid_until, CrowdfundingError::ContributionExpired);
+        require!(contribution.seq == expected_seq, CrowdfundingError::ContributionSeqMismatch);
+        require!(
+            contribution.custodian == Pubkey::default(),
+            CrowdfundingError::CustodianMismatch
+        );
+
+        // Same reduced path as contribute_batch: no holder proof, no delegated spending
+        require!(!campaign.is_gated(clock.unix_timestamp), CrowdfundingError::GateProofRequired);
+        require!(!campaign.requires_cooldown(amount), CrowdfundingError::CooldownRequired);
+        campaign.check_contributor_cap(contribution.amount, amount, 1)?;
+
+        credit_contribution(
+            campaign,
+            contribution,
+            &ctx.accounts.contributor_sketch,
+            ctx.accounts.contributor.key(),
+            amount,
+            clock.unix_timestamp,
+        )?;
+
+        check_not_frozen(&ctx.accounts.contributor_token_account, &ctx.accounts.campaign_vault)?;
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.contributor_token_account.to_account_info(),
+            to: ctx.accounts.campaign_vault.to_account_info(),
+            authority: ctx.accounts.contributor.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.campaign_vault, vault_before.checked_add(amount))?;
+
+        Ok(())
+    }
+
+    /// Contribute from a cold wallet's token account through a pre-approved SPL delegate.
+    ///
+    /// The operational key signs and pays rent; the contribution is credited to the owner of
+    /// the source token account.
+    pub fn contribute_delegated(ctx: Context<ContributeDelegated>, amount: u64) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+        let source = &ctx.accounts.source_token_account;
+        let operator = ctx.accounts.operator.key();
+        let cold_wallet = source.owner;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            source.delegate.contains(&operator) && source.delegated_amount >= amount,
+            CrowdfundingError::DelegateNotApproved
+        );
+        require!(
+            contribution.custodian == Pubkey::default(),
+            CrowdfundingError::CustodianMismatch
+        );
+
+        // The cold wallet never signs, so it cannot present a holder proof
+        require!(!campaign.is_gated(clock.unix_timestamp), CrowdfundingError::GateProofRequired);
+        require!(!campaign.requires_cooldown(amount), CrowdfundingError::CooldownRequired);
+        campaign.check_contributor_cap(contribution.amount, amount, 1)?;
+
+        credit_contribution(
+            campaign,
+            contribution,
+            &ctx.accounts.contributor_sketch,
+            cold_wallet,
+            amount,
+            clock.unix_timestamp,
+        )?;
+
+        check_not_frozen(&ctx.accounts.source_token_account, &ctx.accounts.campaign_vault)?;
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.source_token_account.to_account_info(),
+            to: ctx.accounts.campaign_vault.to_account_info(),
+            authority: ctx.accounts.operator.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+        expect_balance_after_cpi(&mut ctx.accounts.campaign_vault, vault_before.checked_add(amount))?;
+
+        emit!(DelegatedContributionMade {
+            campaign: campaign.key(),
+            contributor: cold_wallet,
+            operator,
+            amount,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_post_mortem_required(
+        ctx: Context<UpdateConfig>,
+        required: bool,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        config.require_post_mortem = required;
+        let config_key = config.key();
+
+        emit!(PostMortemRequirementSet { required });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetPostMortemRequired,
+            config_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    /// Commits the hash of an off-chain post-mortem for a failed campaign. Posting is
+    /// one-shot; the profile counts it towards the creator's failed campaigns.
+    pub fn submit_post_mortem(ctx: Context<SubmitPostMortem>, post_mortem_hash: [u8; 32]) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let profile = &mut ctx.accounts.creator_profile;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // Only failures recorded at finalization owe a post-mortem
+        require!(
+            campaign.finalized_at != 0
+                && campaign.settlement_outcome() != types::SettlementOutcome::Succeeded,
+            CrowdfundingError::PostMortemNotApplicable
+        );
+        require!(post_mortem_hash != [0u8; 32], CrowdfundingError::InvalidPostMortem);
+        require!(
+            campaign.post_mortem_hash == [0u8; 32],
+            CrowdfundingError::PostMortemAlreadyPosted
+        );
+
+        campaign.post_mortem_hash = post_mortem_hash;
+        profile.post_mortems_posted += 1;
+
+        emit!(PostMortemPosted {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            post_mortem_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Opens the unique-contributor sketch for a campaign. Anyone may pay for it.
+    pub fn init_contributor_sketch(ctx: Context<InitContributorSketch>) -> Result<()> {
+        let sketch = &mut ctx.accounts.sketch;
+        sketch.campaign = ctx.accounts.campaign.key();
+
+        Ok(())
+    }
+
+    /// Rebuilds the campaign's keyword bloom filter from scratch. Keywords are matched
+    /// case-insensitively; clients test candidates with `Campaign::may_match_keyword`.
+    pub fn set_search_keywords(ctx: Context<TeamAction>, keywords: Vec<String>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        check_team_role(
+            campaign,
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.team_member,
+            TeamMember::CAN_EDIT_METADATA,
+        )?;
+
+        require!(
+            keywords.len() <= Campaign::MAX_KEYWORDS,
+            CrowdfundingError::TooManyKeywords
+        );
+
+        let mut bloom = [0u8; 32];
+        for keyword in &keywords {
+            require!(
+                !keyword.is_empty() && keyword.len() <= Campaign::MAX_KEYWORD_LEN,
+                CrowdfundingError::InvalidKeyword
+            );
+            for bit in Campaign::keyword_bits(keyword) {
+                bloom[bit / 8] |= 1 << (bit % 8);
+            }
+        }
+        campaign.keyword_bloom = bloom;
+
+        emit!(CampaignMetadataEdited {
+            campaign: campaign.key(),
+            editor: ctx.accounts.signer.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn set_surplus_policy(
+        ctx: Context<UpdateConfig>,
+        policy: SurplusPolicy,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        config.surplus_policy = policy;
+        let config_key = config.key();
+
+        emit!(SurplusPolicySet { policy });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetSurplusPolicy,
+            config_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    /// Routes tokens sent straight to the vault, outside any contribution, according to the
+    /// platform's surplus policy. Anyone may call it.
+    pub fn reconcile_vault(ctx: Context<ReconcileVault>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Once pro-rata refunds fix a payout ratio the expected balance is no longer exact
+        require!(
+            campaign.prorata_denominator == 0,
+            CrowdfundingError::ProrataRefundsAlreadyStarted
+        );
+
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        let surplus = vault_before.saturating_sub(campaign.tracked_vault_balance());
+        require!(surplus > 0, CrowdfundingError::NoVaultSurplus);
+
+        let policy = ctx.accounts.config.surplus_policy;
+        match policy {
+            SurplusPolicy::Backers => {
+                // Scale every outstanding refund up so the surplus is shared pro rata
+                let refunds_open = campaign.is_stalled
+                    || campaign.is_frozen
+                    || (campaign.has_ended(clock.unix_timestamp) && !campaign.is_successful);
+                let outstanding = campaign.outstanding_contributions();
+                require!(refunds_open && outstanding > 0, CrowdfundingError::SurplusPolicyUnavailable);
+
+                campaign.prorata_numerator = outstanding + surplus;
+                campaign.prorata_denominator = outstanding;
+            }
+            SurplusPolicy::Treasury | SurplusPolicy::Creator => {
+                let destination = ctx.accounts.destination_token_account.as_ref()
+                    .ok_or(CrowdfundingError::InvalidSurplusDestination)?;
+                let expected_owner = if policy == SurplusPolicy::Treasury {
+                    ctx.accounts.config.treasury
+                } else {
+                    campaign.creator
+                };
+                require!(
+                    destination.owner == expected_owner
+                        && destination.mint == ctx.accounts.campaign_vault.mint,
+                    CrowdfundingError::InvalidSurplusDestination
+                );
+
+                // Seeds for PDA vault
+                let campaign_key = campaign.key();
+                let seeds = &[
+                    b"vault",
+                    campaign_key.as_ref(),
+                    &[ctx.bumps.campaign_vault],
+                ];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.campaign_vault.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: ctx.accounts.campaign_vault.to_account_info(),
+                };
+
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                token::transfer(cpi_ctx, surplus)?;
+                expect_balance_after_cpi(
+                    &mut ctx.accounts.campaign_vault,
+                    vault_before.checked_sub(surplus),
+                )?;
+            }
+        }
+
+        emit!(VaultReconciled {
+            campaign: campaign.key(),
+            policy,
+            surplus,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    /// Publishes one metadata variant for split testing. Funds from every variant pool into
+    /// the campaign's single vault; contributions carry the variant they came through.
+    pub fn set_variant_metadata(
+        ctx: Context<SetVariantMetadata>,
+        variant: u8,
+        uri_hash: [u8; 32],
+    ) -> Result<()> {
+        let metadata = &mut ctx.accounts.variant_metadata;
+
+        // Check permissions
+        check_team_role(
+            &ctx.accounts.campaign,
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.team_member,
+            TeamMember::CAN_EDIT_METADATA,
+        )?;
+
+        require!(variant < VariantMetadata::MAX_VARIANTS, CrowdfundingError::InvalidVariant);
+
+        metadata.campaign = ctx.accounts.campaign.key();
+        metadata.variant = variant;
+        metadata.uri_hash = uri_hash;
+        metadata.updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(VariantMetadataSet {
+            campaign: metadata.campaign,
+            variant,
+            uri_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Attaches a child campaign to an umbrella campaign. Both creators sign, and the child's
+    /// parameters must still be unlocked because the share changes what backers fund.
+    pub fn set_parent_campaign(ctx: Context<SetParentCampaign>, share_bps: u16) -> Result<()> {
+        let parent = &ctx.accounts.parent;
+        let child = &mut ctx.accounts.child;
+
+        // Check permissions
+        require!(
+            child.creator == ctx.accounts.child_creator.key()
+                && parent.creator == ctx.accounts.parent_creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!child.parameters_locked(), CrowdfundingError::ParametersLocked);
+        // Bundles are one level deep
+        require!(
+            parent.key() != child.key() && parent.parent.is_none(),
+            CrowdfundingError::InvalidParentCampaign
+        );
+        require!(
+            share_bps <= Campaign::MAX_PARENT_SHARE_BPS,
+            CrowdfundingError::InvalidParentShare
+        );
+        // The share is paid in the child's token, so the parent has to raise the same one
+        require!(
+            ctx.accounts.child_vault.mint == ctx.accounts.parent_vault.mint,
+            CrowdfundingError::ParentMintMismatch
+        );
+
+        child.parent = Some(parent.key());
+        child.parent_share_bps = share_bps;
+
+        emit!(ParentCampaignSet {
+            campaign: child.key(),
+            parent: Some(parent.key()),
+            share_bps,
+        });
+
+        Ok(())
+    }
+
+    pub fn clear_parent_campaign(ctx: Context<UpdateCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        campaign.parent = None;
+        campaign.parent_share_bps = 0;
+
+        emit!(ParentCampaignSet {
+            campaign: campaign.key(),
+            parent: None,
+            share_bps: 0,
+        });
+
+        Ok(())
+    }
+
+    /// Crank: adds a child's growth since its last roll-up to the parent's bundle totals.
+    pub fn roll_up_child(ctx: Context<RollUpChild>) -> Result<()> {
+        let child = &ctx.accounts.child;
+        let bundle = &mut ctx.accounts.bundle;
+        let member = &mut ctx.accounts.bundle_member;
+
+        if member.child == Pubkey::default() {
+            member.parent = ctx.accounts.parent.key();
+            member.child = child.key();
+            bundle.parent = ctx.accounts.parent.key();
+            bundle.children_count += 1;
+        }
+
+        // Raised amounts and backer counts only grow, so deltas never double count
+        let raised_delta = child.current_amount.saturating_sub(member.last_raised);
+        let backers_delta = child.contributors_count.saturating_sub(member.last_backers);
+        bundle.total_raised = bundle.total_raised
+            .checked_add(raised_delta)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        bundle.total_backers += backers_delta as u64;
+        bundle.updated_at = Clock::get()?.unix_timestamp;
+
+        member.last_raised = child.current_amount;
+        member.last_backers = child.contributors_count;
+
+        emit!(BundleRolledUp {
+            parent: bundle.parent,
+            child: child.key(),
+            total_raised: bundle.total_raised,
+            total_backers: bundle.total_backers,
+            decimals: ctx.accounts.parent.decimals,
+        });
+
+        Ok(())
+    }
+
+    /// Gates the withdrawal on another program's state: `account` must be owned by
+    /// `owner_program` and hold `expected` at byte `offset` when funds are released.
+    pub fn set_release_condition(
+        ctx: Context<SetReleaseCondition>,
+        account: Pubkey,
+        owner_program: Pubkey,
+        offset: u32,
+        expected: Vec<u8>,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+        require!(
+            !expected.is_empty() && expected.len() <= ReleaseCondition::MAX_EXPECTED_LEN,
+            CrowdfundingError::InvalidReleaseCondition
+        );
+
+        let condition = &mut ctx.accounts.release_condition;
+        condition.campaign = campaign.key();
+        condition.account = account;
+        condition.owner_program = owner_program;
+        condition.offset = offset;
+        condition.expected = expected.clone();
+        campaign.has_release_condition = true;
+
+        emit!(ReleaseConditionSet {
+            campaign: campaign.key(),
+            account,
+            owner_program,
+            offset,
+            expected,
+        });
+
+        Ok(())
+    }
+
+    pub fn clear_release_condition(ctx: Context<ClearReleaseCondition>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.parameters_locked(), CrowdfundingError::ParametersLocked);
+
+        campaign.has_release_condition = false;
+
+        emit!(ReleaseConditionCleared {
+            campaign: campaign.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Escrows a reward drop for a withdrawn campaign's backers. Each backer can claim a
+    /// share proportional to their contribution between `claim_start` and `claim_end`.
+    pub fn create_distribution(
+        ctx: Context<CreateDistribution>,
+        distribution_id: u32,
+        total_amount: u64,
+        claim_start: i64,
+        claim_end: i64,
+        unclaimed_to_treasury: bool,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        // Shares are fixed against the final raise, which only stops moving at withdrawal
+        require!(campaign.is_withdrawn, CrowdfundingError::BackerSetNotFinal);
+        require!(total_amount > 0, CrowdfundingError::InvalidDistributionAmount);
+        require!(
+            claim_start < claim_end && claim_end > clock.unix_timestamp,
+            CrowdfundingError::InvalidClaimWindow
+        );
+
+        let distribution = &mut ctx.accounts.distribution;
+        distribution.campaign = campaign.key();
+        distribution.distribution_id = distribution_id;
+        distribution.reward_mint = ctx.accounts.reward_mint.key();
+        distribution.total_amount = total_amount;
+        distribution.raised_basis = campaign.current_amount;
+        distribution.claim_start = claim_start;
+        distribution.claim_end = claim_end;
+        distribution.unclaimed_to_treasury = unclaimed_to_treasury;
+        campaign.open_distributions += 1;
+
+        let vault_before = ctx.accounts.distribution_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.creator_token_account.to_account_info(),
+            to: ctx.accounts.distribution_vault.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, total_amount)?;
+        expect_balance_after_cpi(
+            &mut ctx.accounts.distribution_vault,
+            vault_before.checked_add(total_amount),
+        )?;
+
+        emit!(DistributionCreated {
+            campaign: campaign.key(),
+            distribution: ctx.accounts.distribution.key(),
+            reward_mint: ctx.accounts.reward_mint.key(),
+            total_amount,
+            claim_start,
+            claim_end,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_distribution(ctx: Context<ClaimDistribution>) -> Result<()> {
+        let distribution = &mut ctx.accounts.distribution;
+        let clock = Clock::get()?;
+
+        require!(!distribution.is_closed, CrowdfundingError::DistributionClosed);
+        require!(
+            clock.unix_timestamp >= distribution.claim_start
+                && clock.unix_timestamp < distribution.claim_end,
+            CrowdfundingError::ClaimWindowNotOpen
+        );
+
+        let allocation = distribution.allocation_for(ctx.accounts.contribution.amount);
+        require!(allocation > 0, CrowdfundingError::NothingToClaim);
+
+        let distribution_key = distribution.key();
+        let seeds = &[
+            b"distribution_vault",
+            distribution_key.as_ref(),
+            &[ctx.bumps.distribution_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let vault_before = ctx.accounts.distribution_vault.amount;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.distribution_vault.to_account_info(),
+            to: ctx.accounts.backer_token_account.to_account_info(),
+            authority: ctx.accounts.distribution_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, allocation)?;
+        expect_balance_after_cpi(
+            &mut ctx.accounts.distribution_vault,
+            vault_before.checked_sub(allocation),
+        )?;
+
+        // The receipt's `init` is what stops a second claim
+        let receipt = &mut ctx.accounts.claim_receipt;
+        receipt.distribution = distribution_key;
+        receipt.backer = ctx.accounts.backer.key();
+        receipt.amount = allocation;
+
+        distribution.claimed_amount = distribution.claimed_amount
+            .checked_add(allocation)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        distribution.claims_count += 1;
+
+        emit!(DistributionClaimed {
+            distribution: distribution_key,
+            backer: receipt.backer,
+            amount: allocation,
+        });
+
+        Ok(())
+    }
+
+    /// After the window ends, anyone can sweep what was not claimed to the creator or the
+    /// treasury, as chosen when the drop was created.
+    pub fn close_distribution_window(ctx: Context<CloseDistributionWindow>) -> Result<()> {
+        let distribution = &mut ctx.accounts.distribution;
+        let clock = Clock::get()?;
+
+        require!(!distribution.is_closed, CrowdfundingError::DistributionClosed);
+        require!(
+            clock.unix_timestamp >= distribution.claim_end,
+            CrowdfundingError::ClaimWindowStillOpen
+        );
+
+        let expected_owner = if distribution.unclaimed_to_treasury {
+            ctx.accounts.config.treasury
+        } else {
+            ctx.accounts.campaign.creator
+        };
+        require!(
+            ctx.accounts.destination_token_account.owner == expected_owner,
+            CrowdfundingError::InvalidDistributionDestination
+        );
+
+        // Rounding dust is swept along with unclaimed allocations
+        let unclaimed = ctx.accounts.distribution_vault.amount;
+        if unclaimed > 0 {
+            let distribution_key = distribution.key();
+            let seeds = &[
+                b"distribution_vault",
+                distribution_key.as_ref(),
+                &[ctx.bumps.distribution_vault],
+            ];
+            let signer_seeds = &[&seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: ctx.accounts.distribution_vault.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, unclaimed)?;
+            expect_balance_after_cpi(&mut ctx.accounts.distribution_vault, Some(0))?;
+        }
+
+        distribution.unclaimed_amount = unclaimed;
+        distribution.is_closed = true;
+        ctx.accounts.campaign.open_distributions -= 1;
+
+        emit!(DistributionWindowClosed {
+            campaign: distribution.campaign,
+            distribution: distribution.key(),
+            claimed_amount: distribution.claimed_amount,
+            unclaimed_amount: unclaimed,
+            to_treasury: distribution.unclaimed_to_treasury,
+        });
+
+        Ok(())
+    }
+
+    /// Crank: refreshes the campaign's compact `StatusFeed` for programs that poll it.
+    pub fn publish_status(ctx: Context<PublishStatus>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let feed = &mut ctx.accounts.status_feed;
+        let clock = Clock::get()?;
+
+        let funded_bps = if campaign.target_amount == 0 {
+            0
+        } else {
+            (campaign.current_amount as u128 * 10_000 / campaign.target_amount as u128)
+                .min(u16::MAX as u128) as u16
+        };
+
+        let mut flags = 0;
+        if campaign.is_successful {
+            flags |= StatusFeed::FLAG_SUCCESSFUL;
+        }
+        if campaign.is_withdrawn {
+            flags |= StatusFeed::FLAG_WITHDRAWN;
+        }
+        if campaign.is_frozen {
+            flags |= StatusFeed::FLAG_FROZEN;
+        }
+        if campaign.is_stalled {
+            flags |= StatusFeed::FLAG_STALLED;
+        }
+        if campaign.has_ended(clock.unix_timestamp) {
+            flags |= StatusFeed::FLAG_ENDED;
+        }
+
+        feed.campaign = campaign.key();
+        feed.funded_bps = funded_bps;
+        feed.backers = campaign.contributors_count;
+        feed.seconds_left = campaign.end_time.saturating_sub(clock.unix_timestamp).max(0) as u32;
+        feed.flags = flags;
+        feed.updated_slot = clock.slot;
+
+        Ok(())
+    }
+
+    /// Registers the key that can later recover the campaign. It can only be set once, so a
+    /// leaked creator key cannot replace it.
+    pub fn set_recovery_key(ctx: Context<UpdateCampaign>, recovery_key: Pubkey) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(
+            campaign.recovery_key == Pubkey::default(),
+            CrowdfundingError::RecoveryKeyAlreadySet
+        );
+        require!(
+            recovery_key != Pubkey::default() && recovery_key != campaign.creator,
+            CrowdfundingError::InvalidRecoveryKey
+        );
+
+        campaign.recovery_key = recovery_key;
+
+        emit!(RecoveryKeySet {
+            campaign: campaign.key(),
+            recovery_key,
+        });
+
+        Ok(())
+    }
+
+    /// Lets a creator who suspects their key leaked stop all withdrawals at once. Only
+    /// `rotate_creator_key` lifts it, so whoever holds the leaked key can't undo it.
+    pub fn emergency_freeze_by_creator(ctx: Context<UpdateCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(!campaign.creator_frozen, CrowdfundingError::CreatorFrozen);
+
+        campaign.creator_frozen = true;
+
+        emit!(CreatorFrozen {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+        });
+
+        Ok(())
+    }
+
+    /// Moves a campaign to a fresh creator key. Needs the pre-registered recovery key and the
+    /// admin together, and clears a creator self-freeze.
+    pub fn rotate_creator_key(
+        ctx: Context<RotateCreatorKey>,
+        new_creator: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+        require!(
+            campaign.recovery_key != Pubkey::default()
+                && campaign.recovery_key == ctx.accounts.recovery.key(),
+            CrowdfundingError::InvalidRecoveryKey
+        );
+
+        require!(
+            new_creator != Pubkey::default() && new_creator != campaign.creator,
+            CrowdfundingError::InvalidCreatorKey
+        );
+
+        let old_creator = campaign.creator;
+        campaign.creator = new_creator;
+        campaign.creator_frozen = false;
+
+        emit!(CreatorKeyRotated {
+            campaign: campaign.key(),
+            old_creator,
+            new_creator,
+        });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::RotateCreatorKey,
+            ctx.accounts.campaign.key(),
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    /// Sets a refund aside when the backer's token account is frozen or gone. The amount is
+    /// fixed and earmarked in the vault until `retry_refund` pays it to a working account.
+    pub fn defer_refund(ctx: Context<DeferRefund>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+        let clock = Clock::get()?;
+
+        // Same conditions as refund_contribution
+        if !campaign.is_stalled {
+            require!(
+                campaign.has_ended(clock.unix_timestamp),
+                CrowdfundingError::CampaignStillActive
+            );
+            require!(!campaign.is_successful, CrowdfundingError::CampaignWasSuccessful);
+        }
+        require!(contribution.amount > 0, CrowdfundingError::NoContributionToRefund);
+        require!(
+            contribution.refund_authority() == ctx.accounts.contributor.key(),
+            CrowdfundingError::UnauthorizedRefund
+        );
+
+        // Only an unusable destination justifies deferring; a working one takes the refund
+        let destination = &ctx.accounts.destination;
+        let destination_frozen = if destination.data_is_empty() || *destination.owner != token::ID {
+            false
+        } else {
+            let account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+            require!(
+                account.owner == contribution.refund_owner(),
+                CrowdfundingError::InvalidRefundDestination
+            );
+            require!(account.is_frozen(), CrowdfundingError::RefundDestinationUsable);
+            true
+        };
+
+        let refund_amount = campaign.refund_amount_for(
+            contribution.amount,
+            ctx.accounts.campaign_vault.amount,
+        )?;
+
+        campaign.total_refunded = campaign.total_refunded
+            .checked_add(contribution.amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        campaign.deferred_refunds = campaign.deferred_refunds
+            .checked_add(refund_amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        contribution.deferred_refund = refund_amount;
+        contribution.amount = 0;
+
+        emit!(RefundDeferred {
+            campaign: campaign.key(),
+            contributor: contribution.contributor,
+            amount: refund_amount,
+            destination_frozen,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn retry_refund(ctx: Context<RetryRefund>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &mut ctx.accounts.contribution;
+
+        require!(contribution.deferred_refund > 0, CrowdfundingError::NoDeferredRefund);
+        require!(
+            contribution.refund_authority() == ctx.accounts.contributor.key(),
+            CrowdfundingError::UnauthorizedRefund
+        );
+
+        // Checked by hand so a missing account and a frozen one fail with distinct errors
+        let destination = &ctx.accounts.destination;
+        require!(
+            !destination.data_is_empty() && *destination.owner == token::ID,
+            CrowdfundingError::RefundDestinationMissing
+        );
+        let account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+        require!(
+            account.owner == contribution.refund_owner(),
+            CrowdfundingError::InvalidRefundDestination
+        );
+        require!(
+            account.mint == ctx.accounts.campaign_vault.mint,
+            CrowdfundingError::RefundMintMismatch
+        );
+        require!(!account.is_frozen(), CrowdfundingError::RefundDestinationFrozen);
+
+        check_vault_authorities(&ctx.accounts.campaign_vault)?;
+        let vault_before = ctx.accounts.campaign_vault.amount;
+        let refund_amount = contribution.deferred_refund;
+
+        // Seeds for PDA vault
+        let campaign_key = campaign.key();
+        let seeds = &[
+            b"vault",
+            campaign_key.as_ref(),
+            &[ctx.bumps.campaign_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.campaign_vault.to_account_info(),
+            to: ctx.accounts.destination.to_account_info(),
+            authority: ctx.accounts.campaign_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, refund_amount)?;
+        expect_balance_after_cpi(
+            &mut ctx.accounts.campaign_vault,
+            vault_before.checked_sub(refund_amount),
+        )?;
+
+        campaign.deferred_refunds -= refund_amount;
+        contribution.deferred_refund = 0;
+
+        emit!(ContributionRefunded {
+            campaign: campaign.key(),
+            contributor: contribution.contributor,
+            amount: refund_amount,
+            decimals: campaign.decimals,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_mint_authority_policy(
+        ctx: Context<UpdateConfig>,
+        forbid: bool,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        config.forbid_mint_authorities = forbid;
+        let config_key = config.key();
+
+        emit!(MintAuthorityPolicySet { forbid });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetMintAuthorityPolicy,
+            config_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    /// Appoints the grant operator allowed to create campaigns in other creators' names.
+    /// `Pubkey::default()` removes the role.
+    pub fn set_campaign_operator(
+        ctx: Context<UpdateConfig>,
+        operator: Pubkey,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        // Check permissions
+        require!(
+            config.admin == ctx.accounts.admin.key(),
+            CrowdfundingError::UnauthorizedAdmin
+        );
+
+        config.campaign_operator = operator;
+        let config_key = config.key();
+
+        emit!(CampaignOperatorSet { operator });
+
+        record_admin_action(
+            &mut ctx.accounts.admin_action,
+            &mut ctx.accounts.config,
+            ctx.accounts.admin.key(),
+            AdminActionKind::SetCampaignOperator,
+            config_key,
+            memo_hash,
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates a campaign owned by `creator`, paid for by the platform's campaign operator.
+    /// Grant programs send one of these per grantee, packing several into each transaction.
+    pub fn initialize_campaign_for(
+        ctx: Context<InitializeCampaignFor>,
+        creator: Pubkey,
+        title: String,
+        description: String,
+        params: CampaignParams,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let operator = ctx.accounts.operator.key();
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.campaign_operator != Pubkey::default()
+                && ctx.accounts.config.campaign_operator == operator,
+            CrowdfundingError::UnauthorizedOperator
+        );
+        require!(
+            ctx.accounts.grant_creator.operator == operator,
+            CrowdfundingError::GrantCreatorOperatorMismatch
+        );
+
+        setup_campaign(
+            &mut ctx.accounts.campaign,
+            &ctx.accounts.config,
+            creator,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_minimum,
+            &ctx.accounts.creator_profile,
+            ctx.accounts.mint_metadata.as_deref(),
+            title,
+            description,
+            &params,
+            clock.unix_timestamp,
+        )?;
+
+        // Nothing can be raised in the creator's name until they accept it
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.created_by = operator;
+        campaign.pending_acceptance = true;
+
+        emit!(CampaignCreatedByOperator {
+            campaign: campaign.key(),
+            creator,
+            operator,
+        });
+
+        Ok(())
+    }
+
+    /// Lists a grantee the campaign operator may open campaigns for.
+    pub fn list_grant_creator(ctx: Context<ListGrantCreator>, creator: Pubkey) -> Result<()> {
+        let listing = &mut ctx.accounts.grant_creator;
+        let operator = ctx.accounts.operator.key();
+
+        // Check permissions
+        require!(
+            ctx.accounts.config.campaign_operator != Pubkey::default()
+                && ctx.accounts.config.campaign_operator == operator,
+            CrowdfundingError::UnauthorizedOperator
+        );
+
+        listing.operator = operator;
+        listing.creator = creator;
+        listing.listed_at = Clock::get()?.unix_timestamp;
+
+        emit!(GrantCreatorUpdated {
+            operator,
+            creator,
+            listed: true,
+        });
+
+        Ok(())
+    }
+
+    pub fn unlist_grant_creator(ctx: Context<UnlistGrantCreator>) -> Result<()> {
+        // Check permissions
+        require!(
+            ctx.accounts.grant_creator.operator == ctx.accounts.operator.key(),
+            CrowdfundingError::UnauthorizedOperator
+        );
+
+        emit!(GrantCreatorUpdated {
+            operator: ctx.accounts.operator.key(),
+            creator: ctx.accounts.grant_creator.creator,
+            listed: false,
+        });
+
+        Ok(())
+    }
+
+    /// Activates an operator-created campaign. Only the named creator can accept, so a
+    /// campaign opened in someone's name can't raise funds without their consent.
+    pub fn accept_campaign(ctx: Context<UpdateCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(campaign.pending_acceptance, CrowdfundingError::CampaignNotPendingAcceptance);
+
+        // The full duration runs from acceptance, not from when the operator created it; a
+        // start the operator scheduled for later is kept
+        let duration = campaign.end_time - campaign.start_time;
+        let start_time = campaign.start_time.max(clock.unix_timestamp);
+        campaign.pending_acceptance = false;
+        campaign.start_time = start_time;
+        campaign.set_end_time(start_time + duration);
+
+        emit!(CampaignAccepted {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            operator: campaign.created_by,
+            start_time: campaign.start_time,
+            end_time: campaign.end_time,
+        });
+
+        Ok(())
+    }
+
+    /// Lets the named creator turn down an operator-created campaign. The campaign and its vault
+    /// are closed with their rent returned to the operator, freeing the `[creator, title]` address.
+    pub fn reject_campaign(ctx: Context<RejectCampaign>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        // Check permissions
+        require!(
+            campaign.creator == ctx.accounts.creator.key(),
+            CrowdfundingError::UnauthorizedCreator
+        );
+
+        require!(campaign.pending_acceptance, CrowdfundingError::CampaignNotPendingAcceptance);
+
+        // Seeds for PDA vault
+        let campaign_key = campaign.key();
+        let seeds = &[
+            b"vault",
+            campaign_key.as_ref(),
+            &[ctx.bumps.campaign_vault],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Pending campaigns take no contributions, so anything here was sent straight to the
+        // vault; it goes to the creator rather than letting dust block the rejection
+        let stray = ctx.accounts.campaign_vault.amount;
+        if stray > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.campaign_vault.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.campaign_vault.to_account_info(),
+            };
+
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, stray)?;
+            expect_balance_after_cpi(&mut ctx.accounts.campaign_vault, Some(0))?;
+        }
+
+        // Close the empty vault, returning its rent to the operator that paid for it
+        let cpi_accounts = CloseAccount {
+            account: ctx.accounts.campaign_vault.to_account_info(),
+            destination: ctx.accounts.operator.to_account_info(),
+            authority: ctx.accounts.campaign_vault.to_account_info(),
+        };
+
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::close_account(cpi_ctx)?;
+
+        emit!(CampaignRejected {
+            campaign: campaign_key,
+            creator: ctx.accounts.creator.key(),
+            operator: ctx.accounts.operator.key(),
+        });
+
+        Ok(())
+    }
+}
+
+fn setup_campaign(
+    campaign: &mut Account<Campaign>,
+    config: &GlobalConfig,
+    creator: Pubkey,
+    mint: &Account<Mint>,
+    mint_minimum: &AccountInfo,
+    creator_profile: &AccountInfo,
+    mint_metadata: Option<&AccountInfo>,
+    title: String,
+    description: String,
+    params: &CampaignParams,
+    now: i64,
+) -> Result<()> {
+    // Input validation
+    require!(title.len() <= 100, CrowdfundingError::TitleTooLong);
+    require!(description.len() <= 500, CrowdfundingError::DescriptionTooLong);
+    params.validate()?;
+
+    // A 1-token target means very different things across mints
+    let min_target_amount = mint_minimum_target(mint_minimum)?;
+    require!(
+        params.target_amount >= min_target_amount,
+        CrowdfundingError::TargetBelowMintMinimum
+    );
+
+    if params.require_allowlisted_mint {
+        require!(
+            config.mint_allowlist.contains(&mint.key()),
+            CrowdfundingError::MintNotAllowlisted
+        );
+    }
+
+    check_post_mortem_compliance(config, creator_profile)?;
+
+    // A live mint authority can inflate the currency and a freeze authority can lock backers out
+    let mint_authority_active = mint.mint_authority.is_some();
+    let freeze_authority_active = mint.freeze_authority.is_some();
+    if config.forbid_mint_authorities {
+        require!(
+            !mint_authority_active && !freeze_authority_active,
+            CrowdfundingError::MintAuthorityForbidden
+        );
+    }
+
+    // The display currency is fixed at creation so frontends can't be shown a spoofed one later
+    let (mint_symbol, mint_uri_hash) = match mint_metadata {
+        Some(metadata) => read_mint_display(&mint.key(), metadata)?,
+        None => ([0u8; 10], [0u8; 32]),
+    };
+
+    campaign.creator = creator;
+    campaign.title = title;
+    campaign.description = description;
+    campaign.target_amount = params.target_amount;
+    campaign.current_amount = 0;
+    campaign.start_time = now;
+    campaign.set_end_time(now + (params.duration_days as i64 * 24 * 60 * 60));
+    campaign.is_successful = false;
+    campaign.is_withdrawn = false;
+    campaign.contributors_count = 0;
+    campaign.require_allowlisted_mint = params.require_allowlisted_mint;
+    campaign.is_frozen = false;
+    campaign.survey_closed_at = 0;
+    campaign.grace_period_seconds = params.grace_period_seconds;
+    campaign.grace_max_shortfall_bps = params.grace_max_shortfall_bps;
+    campaign.max_contributors = params.max_contributors;
+    campaign.total_refunded = 0;
+    campaign.prorata_numerator = 0;
+    campaign.prorata_denominator = 0;
+    campaign.series = Pubkey::default();
+    campaign.fee_override_bps = None;
+    campaign.inactivity_timeout = 0;
+    campaign.succeeded_at = 0;
+    campaign.is_stalled = false;
+    campaign.approvers = Vec::new();
+    campaign.approval_threshold = 0;
+    campaign.max_per_contributor = 0;
+    campaign.reputation_scaled_cap = false;
+    campaign.finalized_at = 0;
+    campaign.category = 0;
+    campaign.featured_weight = 0;
+    campaign.fee_discount_bps = 0;
+    campaign.fee_collected = 0;
+    campaign.matched_amount = 0;
+    campaign.gate_mint = Pubkey::default();
+    campaign.gate_is_collection = false;
+    campaign.priority_ends_at = 0;
+    campaign.holder_only = false;
+    campaign.streak_bonus_bps = 0;
+    campaign.followers_count = 0;
+    campaign.faq_hash = [0; 32];
+    campaign.faq_version = 0;
+    campaign.snipe_window_seconds = 0;
+    campaign.snipe_extension_seconds = 0;
+    campaign.max_total_extension_seconds = 0;
+    campaign.total_extended_seconds = 0;
+    campaign.cooldown_threshold = 0;
+    campaign.cooldown_seconds = 0;
+    // Cached so events carry enough context to render UI amounts without a mint lookup
+    campaign.decimals = mint.decimals;
+    campaign.content_key_hash = [0; 32];
+    campaign.min_target_amount = min_target_amount;
+    campaign.is_verified = false;
+    campaign.fee_waived = false;
+    campaign.post_mortem_hash = [0u8; 32];
+    campaign.keyword_bloom = [0u8; 32];
+    campaign.mint_symbol = mint_symbol;
+    campaign.mint_uri_hash = mint_uri_hash;
+    campaign.parent = None;
+    campaign.parent_share_bps = 0;
+    campaign.bundle_inflow = 0;
+    campaign.has_release_condition = false;
+    campaign.recovery_key = Pubkey::default();
+    campaign.creator_frozen = false;
+    campaign.deferred_refunds = 0;
+    campaign.mint_authority_active = mint_authority_active;
+    campaign.freeze_authority_active = freeze_authority_active;
+    campaign.created_by = Pubkey::default();
+    campaign.pending_acceptance = false;
+    campaign.approvers_epoch = 0;
+    campaign.rent_reimbursement_per_account = 0;
+    campaign.rent_reimbursement_owed = 0;
+    campaign.open_distributions = 0;
+    campaign.pending_contributions = 0;
+
+    emit!(CampaignCreated {
+        campaign: campaign.key(),
+        creator: campaign.creator,
+        target_amount: campaign.target_amount,
+        end_time: campaign.end_time,
+        grace_period_seconds: campaign.grace_period_seconds,
+        grace_max_shortfall_bps: campaign.grace_max_shortfall_bps,
+        decimals: campaign.decimals,
+        mint_authority_active,
+        freeze_authority_active,
+        max_contributors: campaign.max_contributors,
+    });
+    log!(
+        "create",
+        campaign = campaign.key(),
+        actor = campaign.creator,
+        amount = campaign.target_amount,
+        state = "active",
+    );
+
+    Ok(())
+}
+
+fn process_withdrawal(accounts: &mut WithdrawFunds, vault_bump: u8) -> Result<()> {
+    let campaign = &mut accounts.campaign;
+    let clock = Clock::get()?;
+
+    // Check permissions
+    check_team_role(
+        campaign,
+        &accounts.creator.key(),
+        &accounts.team_member,
+        TeamMember::CAN_WITHDRAW,
+    )
+    .map_err(|_| error!(CrowdfundingError::UnauthorizedWithdrawal))?;
+
+    // Team members can trigger the payout, but it always lands with the creator
+    if accounts.creator.key() != campaign.creator {
+        require!(
+            accounts.creator_token_account.owner == campaign.creator,
+            CrowdfundingError::InvalidPayoutAccount
+        );
+    }
+
+    // Check withdrawal conditions
+    require!(
+        campaign.is_successful || campaign.has_ended(clock.unix_timestamp),
+        CrowdfundingError::WithdrawalConditionsNotMet
+    );
+
+    require!(!campaign.is_withdrawn, CrowdfundingError::AlreadyWithdrawn);
+    require!(!campaign.is_frozen, CrowdfundingError::CampaignFrozen);
+    require!(!campaign.is_stalled, CrowdfundingError::CampaignStalled);
+    require!(!campaign.creator_frozen, CrowdfundingError::CreatorFrozen);
+
+    if campaign.has_release_condition {
+        check_release_condition(
+            accounts.release_condition.as_ref(),
+            accounts.condition_account.as_deref(),
+        )?;
+    }
+
+    check_vault_authorities(&accounts.campaign_vault)?;
+    let vault_balance = accounts.campaign_vault.amount;
+    require!(vault_balance > 0, CrowdfundingError::NoFundsToWithdraw);
+
+    // Platform fee: a negotiated per-campaign override takes precedence over the global schedule,
+    // then any discount the creator redeemed with loyalty points applies
+    campaign.fee_waived = accounts.config.waives_fee_for(campaign);
+    let fee_bps = if campaign.fee_waived {
+        0
+    } else {
+        campaign.fee_override_bps
+            .unwrap_or(accounts.config.effective_fee_bps(clock.unix_timestamp))
+            .saturating_sub(campaign.fee_discount_bps)
+    };
+    let fee_amount = (vault_balance as u128 * fee_bps as u128 / 10_000) as u64;
+    let amount_to_withdraw = vault_balance - fee_amount;
+
+    // Stakers of the platform token earn a share of fees paid in the revenue-share mint. Once
+    // the revenue share is configured for this mint its vault is required, so leaving the
+    // accounts out can't send the whole fee to the treasury
+    let mut revenue_share = if accounts.revenue_share.data_is_empty() {
+        None
+    } else {
+        Some(Account::<RevenueShareConfig>::try_from(&accounts.revenue_share.to_account_info())?)
+    };
+    let staker_share = match &revenue_share {
+        Some(revenue_share) if accounts.campaign_vault.mint == revenue_share.reward_mint => {
+            require!(
+                accounts.revenue_vault.is_some(),
+                CrowdfundingError::RevenueShareAccountsRequired
+            );
+            if revenue_share.total_staked > 0 {
+                (fee_amount as u128 * revenue_share.share_bps as u128 / 10_000) as u64
+            } else {
+                0
+            }
+        }
+        _ => 0,
+    };
+    let treasury_fee = fee_amount - staker_share;
+
+    // Bundled children pass a share of the payout up to a successful umbrella campaign: into
+    // its vault, or to its creator once it has withdrawn. A parent that failed, stalled, was
+    // frozen or no longer exists forgoes the share; while it is still collecting the child has
+    // to wait, so timing the withdrawal can't skip the share
+    let mut parent_share = 0;
+    let mut parent_withdrawn = false;
+    let linked_parent = campaign.parent
+        .filter(|_| campaign.parent_share_bps > 0)
+        .filter(|parent| !is_parent_closed(parent, accounts.closed_parent.as_ref()));
+    if let Some(parent) = linked_parent {
+        let parent_campaign = accounts.parent_campaign.as_ref()
+            .ok_or(CrowdfundingError::ParentAccountsRequired)?;
+        require!(parent_campaign.key() == parent, CrowdfundingError::InvalidParentCampaign);
+        require!(
+            parent_campaign.is_frozen
+                || parent_campaign.is_successful
+                || parent_campaign.has_ended(clock.unix_timestamp),
+            CrowdfundingError::ParentNotSettled
+        );
+
+        if parent_campaign.is_successful && !parent_campaign.is_stalled && !parent_campaign.is_frozen {
+            parent_withdrawn = parent_campaign.is_withdrawn;
+            if parent_withdrawn {
+                let payout = accounts.parent_creator_token_account.as_ref()
+                    .ok_or(CrowdfundingError::ParentAccountsRequired)?;
+                require!(
+                    payout.owner == parent_campaign.creator
+                        && payout.mint == accounts.campaign_vault.mint,
+                    CrowdfundingError::ParentAccountsRequired
+                );
+            } else {
+                let parent_vault = accounts.parent_vault.as_ref()
+                    .ok_or(CrowdfundingError::ParentAccountsRequired)?;
+                let (parent_vault_address, _) =
+                    Pubkey::find_program_address(&[b"vault", parent.as_ref()], &crate::ID);
+                require!(
+                    parent_vault.key() == parent_vault_address,
+                    CrowdfundingError::ParentAccountsRequired
+                );
+                check_vault_authorities(parent_vault)?;
+            }
+
+            parent_share =
+                (amount_to_withdraw as u128 * campaign.parent_share_bps as u128 / 10_000) as u64;
+        }
+    }
+
+    // The rent sponsor is paid back out of the creator's side, up to what is left of it
+    let rent_reimbursement = campaign.rent_reimbursement_owed.min(amount_to_withdraw - parent_share);
+    if rent_reimbursement > 0 {
+        let rent_pool = accounts.rent_pool.as_ref()
+            .ok_or(CrowdfundingError::RentPoolAccountsRequired)?;
+        let sponsor_token_account = accounts.sponsor_token_account.as_ref()
+            .ok_or(CrowdfundingError::RentPoolAccountsRequired)?;
+        require!(
+            sponsor_token_account.owner == rent_pool.sponsor
+                && sponsor_token_account.mint == accounts.campaign_vault.mint,
+            CrowdfundingError::RentPoolAccountsRequired
+        );
+    }
+    let creator_amount = amount_to_withdraw - parent_share - rent_reimbursement;
+
+    // Seeds for PDA vault
+    let campaign_key = campaign.key();
+    let seeds = &[
+        b"vault",
+        campaign_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Transfer platform fee to treasury
+    if treasury_fee > 0 {
+        let cpi_accounts = Transfer {
+            from: accounts.campaign_vault.to_account_info(),
+            to: accounts.treasury_token_account.to_account_info(),
+            authority: accounts.campaign_vault.to_account_info(),
+        };
+
+        let cpi_program = accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, treasury_fee)?;
+        expect_balance_after_cpi(
+            &mut accounts.campaign_vault,
+            Some(amount_to_withdraw + staker_share),
+        )?;
+    }
+
+    // Route the stakers' share to the revenue vault and credit it pro-rata
+    if staker_share > 0 {
+        if let (Some(revenue_share), Some(revenue_vault)) =
+            (&mut revenue_share, &accounts.revenue_vault)
+        {
+            let cpi_accounts = Transfer {
+                from: accounts.campaign_vault.to_account_info(),
+                to: revenue_vault.to_account_info(),
+                authority: accounts.campaign_vault.to_account_info(),
+            };
+
+            let cpi_program = accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, staker_share)?;
+            expect_balance_after_cpi(&mut accounts.campaign_vault, Some(amount_to_withdraw))?;
+
+            revenue_share.record_revenue(staker_share)?;
+            revenue_share.exit(&crate::ID)?;
+
+            emit!(RevenueShared {
+                campaign: campaign.key(),
+                amount: staker_share,
+                total_staked: revenue_share.total_staked,
+                decimals: campaign.decimals,
+            });
+        }
+    }
+
+    // Route the bundle share to the parent vault, tracked there as inflow, or straight to the
+    // parent's creator when the parent has already withdrawn
+    if parent_share > 0 {
+        let destination = if parent_withdrawn {
+            accounts.parent_creator_token_account.as_mut()
+        } else {
+            accounts.parent_vault.as_mut()
+        };
+        if let (Some(parent_campaign), Some(destination)) =
+            (&mut accounts.parent_campaign, destination)
+        {
+            let destination_before = destination.amount;
+            let cpi_accounts = Transfer {
+                from: accounts.campaign_vault.to_account_info(),
+                to: destination.to_account_info(),
+                authority: accounts.campaign_vault.to_account_info(),
+            };
+
+            let cpi_program = accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, parent_share)?;
+            expect_balance_after_cpi(
+                &mut accounts.campaign_vault,
+                Some(creator_amount + rent_reimbursement),
+            )?;
+            expect_balance_after_cpi(destination, destination_before.checked_add(parent_share))?;
+
+            if !parent_withdrawn {
+                parent_campaign.bundle_inflow = parent_campaign.bundle_inflow
+                    .checked_add(parent_share)
+                    .ok_or(CrowdfundingError::AmountOverflow)?;
+            }
+
+            emit!(ParentShareRouted {
+                campaign: campaign.key(),
+                parent: parent_campaign.key(),
+                amount: parent_share,
+                decimals: campaign.decimals,
+            });
+        }
+    }
+
+    // Reimburse the rent sponsor for the Contribution accounts it paid for
+    if rent_reimbursement > 0 {
+        if let (Some(rent_pool), Some(sponsor_token_account)) =
+            (&accounts.rent_pool, &accounts.sponsor_token_account)
+        {
+            let cpi_accounts = Transfer {
+                from: accounts.campaign_vault.to_account_info(),
+                to: sponsor_token_account.to_account_info(),
+                authority: accounts.campaign_vault.to_account_info(),
+            };
+
+            let cpi_program = accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, rent_reimbursement)?;
+            expect_balance_after_cpi(&mut accounts.campaign_vault, Some(creator_amount))?;
+
+            campaign.rent_reimbursement_owed -= rent_reimbursement;
+
+            emit!(RentReimbursed {
+                campaign: campaign.key(),
+                sponsor: rent_pool.sponsor,
+                amount: rent_reimbursement,
+                decimals: campaign.decimals,
+            });
+        }
+    }
+
+    // Transfer funds to campaign creator
+    let cpi_accounts = Transfer {
+        from: accounts.campaign_vault.to_account_info(),
+        to: accounts.creator_token_account.to_account_info(),
+        authority: accounts.campaign_vault.to_account_info(),
+    };
+
+    let cpi_program = accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, creator_amount)?;
+    expect_balance_after_cpi(&mut accounts.campaign_vault, Some(0))?;
+
+    campaign.is_withdrawn = true;
+    campaign.fee_collected = fee_amount;
+
+    emit!(FundsWithdrawn {
+        campaign: campaign.key(),
+        creator: campaign.creator,
+        amount: creator_amount,
+        fee_amount,
+        decimals: campaign.decimals,
+    });
+    log!(
+        "withdraw",
+        campaign = campaign.key(),
+        actor = campaign.creator,
+        amount = creator_amount,
+        fee = fee_amount,
+        state = "withdrawn",
+    );
+
+    Ok(())
+}
+
+fn process_contribute(accounts: &mut Contribute, args: types::ContributeArgs) -> Result<()> {
+    let campaign = &mut accounts.campaign;
+    let contribution = &mut accounts.contribution;
+    let clock = Clock::get()?;
+    let amount = args.amount;
+
+    require!(
+        args.version <= types::CONTRIBUTE_ARGS_VERSION,
+        CrowdfundingError::UnsupportedArgsVersion
+    );
+    if let Some(referrer) = args.referrer {
+        require!(
+            referrer != accounts.contributor.key(),
+            CrowdfundingError::SelfReferral
+        );
+    }
+    if let Some(variant) = args.variant {
+        require!(variant < VariantMetadata::MAX_VARIANTS, CrowdfundingError::InvalidVariant);
+    }
+
+    // Custodial records can only be topped up through their custodian
+    require!(
+        contribution.custodian == Pubkey::default(),
+        CrowdfundingError::CustodianMismatch
+    );
+
+    // Payment processors retry transactions; a repeated order id must not credit twice
+    if let Some(client_order_id) = args.client_order_id {
+        contribution.record_client_order_id(client_order_id)?;
+    }
+
+    // During the priority window (or always, for holder-only campaigns) only holders of the
+    // community token or collection may fund
+    if campaign.is_gated(clock.unix_timestamp) {
+        verify_gate_holder(
+            campaign,
+            &accounts.contributor.key(),
+            &accounts.gate_token_account,
+            accounts.gate_metadata.as_deref(),
+        )?;
+    }
+
+    // Spending from a token account someone else owns (e.g. a PDA that delegated to the
+    // contributor) is only allowed when the owning program is on the composability allowlist
+    let token_owner = accounts.contributor_token_account.owner;
+    if token_owner != accounts.contributor.key() {
+        let owner_info = accounts.token_account_owner.as_ref()
+            .ok_or(CrowdfundingError::TokenOwnerNotAllowlisted)?;
+        let registration = accounts.composable_program.as_ref()
+            .ok_or(CrowdfundingError::TokenOwnerNotAllowlisted)?;
+        require!(
+            owner_info.key() == token_owner && registration.program_id == *owner_info.owner,
+            CrowdfundingError::TokenOwnerNotAllowlisted
+        );
+    }
+
+    // Reimburse rent for freshly created contribution accounts when a sponsor pool exists
+    if contribution.contributor == Pubkey::default() {
+        if let Some(rent_pool) = &mut accounts.rent_pool {
+            let rent = Rent::get()?;
+            let contribution_rent = rent.minimum_balance(Contribution::SIZE);
+            let pool_reserve = rent.minimum_balance(RentPool::SIZE);
+            let pool_info = rent_pool.to_account_info();
+
+            let available = pool_info.lamports().saturating_sub(pool_reserve);
+            if available >= contribution_rent && amount >= rent_pool.min_contribution {
+                **pool_info.try_borrow_mut_lamports()? -= contribution_rent;
+                **accounts.contributor.to_account_info().try_borrow_mut_lamports()? +=
+                    contribution_rent;
+
+                rent_pool.accounts_sponsored += 1;
+                rent_pool.lamports_sponsored = rent_pool.lamports_sponsored
+                    .checked_add(contribution_rent)
+                    .ok_or(CrowdfundingError::AmountOverflow)?;
+                campaign.rent_reimbursement_owed = campaign.rent_reimbursement_owed
+                    .checked_add(campaign.rent_reimbursement_per_account)
+                    .ok_or(CrowdfundingError::AmountOverflow)?;
+            }
+        }
+    }
+
+    // Per-contributor cap, optionally scaled by the backer's reputation tier
+    let cap_multiplier = match &accounts.contributor_profile {
+        Some(profile) if campaign.reputation_scaled_cap => profile.cap_multiplier(),
+        _ => 1,
+    };
+    campaign.check_contributor_cap(contribution.amount, amount, cap_multiplier)?;
+
+    // Whale-sized deposits wait out the cool-down in queue_contribution instead
+    require!(!campaign.requires_cooldown(amount), CrowdfundingError::CooldownRequired);
+
+    credit_contribution(
+        campaign,
+        contribution,
+        &accounts.contributor_sketch,
+        accounts.contributor.key(),
+        amount,
+        clock.unix_timestamp,
+    )?;
+
+    // Loyalty points accrue on the backer's profile when the platform runs a points program
+    if let (Some(profile), Some(points_config)) =
+        (&mut accounts.contributor_profile, &accounts.points_config)
+    {
+        let earned = points_config.points_for(amount);
+        profile.points = profile.points.saturating_add(earned);
+
+        if earned > 0 {
+            emit!(PointsAccrued {
+                contributor: profile.contributor,
+                campaign: campaign.key(),
+                points: earned,
+                balance: profile.points,
+            });
+        }
+    }
+
+    // Transfer tokens to campaign vault
+    check_not_frozen(&accounts.contributor_token_account, &accounts.campaign_vault)?;
+    check_vault_authorities(&accounts.campaign_vault)?;
+    let vault_before = accounts.campaign_vault.amount;
+    let cpi_accounts = Transfer {
+        from: accounts.contributor_token_account.to_account_info(),
+        to: accounts.campaign_vault.to_account_info(),
+        authority: accounts.contributor.to_account_info(),
+    };
+    
+    let cpi_program = accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+    expect_balance_after_cpi(&mut accounts.campaign_vault, vault_before.checked_add(amount))?;
+
+    // DAO treasuries record which proposal approved the spend; the latest one is kept
+    if let Some(funding_reference) = args.funding_reference {
+        accounts.contribution.funding_reference = funding_reference;
+    }
+
+    if args.memo_hash.is_some()
+        || args.referrer.is_some()
+        || args.funding_reference.is_some()
+        || args.variant.is_some()
+    {
+        emit!(ContributionTagged {
+            campaign: accounts.campaign.key(),
+            contributor: accounts.contributor.key(),
+            memo_hash: args.memo_hash,
+            referrer: args.referrer,
+            funding_reference: args.funding_reference,
+            variant: args.variant,
+        });
+    }
+
+    Ok(())
+}
+
+fn credit_contribution(
+    campaign: &mut Account<Campaign>,
+    contribution: &mut Account<Contribution>,
+    sketch: &AccountInfo,
+    contributor: Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    // Check if campaign is active (or inside its grace window)
+    require!(campaign.has_started(now), CrowdfundingError::CampaignNotStarted);
+    require!(!campaign.has_ended(now), CrowdfundingError::CampaignEnded);
+    require!(amount > 0, CrowdfundingError::InvalidContributionAmount);
+    require!(!campaign.is_withdrawn, CrowdfundingError::CampaignAlreadyWithdrawn);
+    require!(!campaign.is_frozen, CrowdfundingError::CampaignFrozen);
+    require!(!campaign.pending_acceptance, CrowdfundingError::CampaignPendingAcceptance);
+
+    // Check if we don't exceed the target
+    let new_total = campaign.current_amount
+        .checked_add(amount)
+        .ok_or(CrowdfundingError::AmountOverflow)?;
+
+    require!(new_total <= campaign.target_amount, CrowdfundingError::ExceedsTarget);
+
+    let was_locked = campaign.parameters_locked();
+
+    // Update contribution state
+    if contribution.amount == 0 {
+        // New contributor; top-ups from existing backers are never capped
+        require!(
+            campaign.max_contributors == 0 || campaign.contributors_count < campaign.max_contributors,
+            CrowdfundingError::ContributorLimitReached
+        );
+        contribution.contributor = contributor;
+        contribution.campaign = campaign.key();
+        campaign.contributors_count += 1;
+        // 1-based arrival order, kept for "founding backer" perks
+        contribution.backer_index = campaign.contributors_count;
+    }
+
+    observe_contributor(sketch, &contributor)?;
+
+    contribution.amount = contribution.amount
+        .checked_add(amount)
+        .ok_or(CrowdfundingError::AmountOverflow)?;
+    // Every credit advances the sequence checked by contribute_sequenced
+    contribution.seq += 1;
+
+    if campaign.streak_bonus_bps > 0 {
+        contribution.record_streak_day(now, campaign.streak_bonus_bps);
+    }
+
+    // Pin the FAQ the backer saw, so disputes can refer to the exact document
+    contribution.terms_hash = campaign.faq_hash;
+    contribution.terms_version = campaign.faq_version;
+
+    campaign.current_amount = new_total;
+
+    // Check if target has been reached
+    if campaign.current_amount >= campaign.target_amount && !campaign.is_successful {
+        campaign.is_successful = true;
+        campaign.succeeded_at = now;
+    }
+
+    let extension = campaign.extend_for_late_contribution(now);
+    if extension > 0 {
+        emit!(DeadlineExtended {
+            campaign: campaign.key(),
+            extension_seconds: extension,
+            end_time: campaign.end_time,
+        });
+    }
+
+    emit!(ContributionMade {
+        campaign: campaign.key(),
+        contributor,
+        amount,
+        total_raised: campaign.current_amount,
+        decimals: campaign.decimals,
+        backer_index: contribution.backer_index,
+    });
+
+    if !was_locked {
+        emit!(ParametersLocked {
+            campaign: campaign.key(),
+            target_amount: campaign.target_amount,
+            end_time: campaign.end_time,
+            fee_override_bps: campaign.fee_override_bps,
+            locked_at: now,
+        });
+    }
+    log!(
+        "contribute",
+        campaign = campaign.key(),
+        actor = contributor,
+        amount = amount,
+        total = campaign.current_amount,
+        successful = campaign.is_successful,
+    );
+
+    Ok(())
+}
+
+/// Whether a bundled child's parent has been archived or closed, shown either by its
+/// `CampaignSummary` or by its now-empty campaign address.
+fn is_parent_closed(parent: &Pubkey, closed_parent: Option<&UncheckedAccount>) -> bool {
+    let account = match closed_parent {
+        Some(account) => account,
+        None => return false,
+    };
+    if account.key() == *parent {
+        return account.data_is_empty();
+    }
+
+    let (summary_address, _) =
+        Pubkey::find_program_address(&[b"summary", parent.as_ref()], &crate::ID);
+    account.key() == summary_address && account.owner == &crate::ID && !account.data_is_empty()
+}
+
+/// Folds a backer into the campaign's `ContributorSketch` once one has been opened. Every credit
+/// path passes the sketch PDA, so leaving it out can't keep a backer off the estimate.
+fn observe_contributor(sketch: &AccountInfo, contributor: &Pubkey) -> Result<()> {
+    if sketch.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut sketch = Account::<ContributorSketch>::try_from(sketch)?;
+    sketch.observe(contributor);
+    sketch.exit(&crate::ID)
+}
+
+/// Vaults are owned by their own PDA and must never carry a delegate or close authority.
+fn check_vault_authorities(vault: &Account<TokenAccount>) -> Result<()> {
+    check_token_authorities(&vault.key(), vault)
+}
+
+fn check_token_authorities(vault_key: &Pubkey, vault: &TokenAccount) -> Result<()> {
+    require!(
+        vault.owner == *vault_key && vault.delegate.is_none() && vault.close_authority.is_none(),
+        CrowdfundingError::UnexpectedVaultAuthority
+    );
+    Ok(())
+}
+
+/// Which of the delegate and close authority `repair_vault_authorities` has to clear.
+fn unexpected_vault_authorities(vault: &TokenAccount) -> (bool, bool) {
+    (vault.delegate.is_some(), vault.close_authority.is_some())
+}
+
+/// Rejects a deposit up front when the mint's freeze authority has frozen either side, so the
+/// backer sees which account is blocked instead of the token program's generic error.
+fn check_not_frozen(source: &Account<TokenAccount>, vault: &Account<TokenAccount>) -> Result<()> {
+    require!(!vault.is_frozen(), CrowdfundingError::VaultFrozen);
+    require!(!source.is_frozen(), CrowdfundingError::ContributorAccountFrozen);
+    Ok(())
+}
+
+/// Re-reads a token account after a transfer CPI and checks it holds exactly `expected`.
+///
+/// Settlement never trusts balances cached before a CPI: a token program with hooks could
+/// otherwise re-enter or skim, leaving the bookkeeping out of sync with the vault.
+fn expect_balance_after_cpi(account: &mut Account<TokenAccount>, expected: Option<u64>) -> Result<()> {
+    account.reload()?;
+    require!(
+        expected == Some(account.amount),
+        CrowdfundingError::UnexpectedBalanceAfterTransfer
+    );
+    Ok(())
+}
+
+/// Integer square root, used for quadratic matching weights.
+fn isqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method, starting from an overestimate
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+/// Passes for the campaign creator, or for a team member of this campaign holding `role`.
+fn check_team_role(
+    campaign: &Account<Campaign>,
+    signer: &Pubkey,
+    team_member: &Option<Account<TeamMember>>,
+    role: u8,
+) -> Result<()> {
+    if campaign.creator == *signer {
+        return Ok(());
+    }
+
+    match team_member {
+        Some(team_member)
+            if team_member.campaign == campaign.key()
+                && team_member.member == *signer
+                && team_member.has_role(role) =>
+        {
+            Ok(())
+        }
+        _ => err!(CrowdfundingError::MissingTeamRole),
+    }
+}
+
+/// Metaplex Token Metadata program, owner of NFT metadata accounts.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+#[derive(AnchorDeserialize)]
+struct MetadataCreator {
+    _address: Pubkey,
+    _verified: bool,
+    _share: u8,
+}
+
+#[derive(AnchorDeserialize)]
+struct MetadataCollection {
+    verified: bool,
+    key: Pubkey,
+}
+
+/// Leading fields of a Metaplex `Metadata` account, up to and including `collection`.
+#[derive(AnchorDeserialize)]
+struct MetadataPrefix {
+    _key: u8,
+    _update_authority: Pubkey,
+    mint: Pubkey,
+    _name: String,
+    symbol: String,
+    uri: String,
+    _seller_fee_basis_points: u16,
+    _creators: Option<Vec<MetadataCreator>>,
+    _primary_sale_happened: bool,
+    _is_mutable: bool,
+    _edition_nonce: Option<u8>,
+    _token_standard: Option<u8>,
+    collection: Option<MetadataCollection>,
+}
+
+/// Checks the contributor holds the campaign's gate token, or an NFT from its verified collection.
+fn verify_gate_holder(
+    campaign: &Campaign,
+    contributor: &Pubkey,
+    gate_token_account: &Option<Account<TokenAccount>>,
+    gate_metadata: Option<&AccountInfo>,
+) -> Result<()> {
+    let token_account = gate_token_account.as_ref()
+        .ok_or(CrowdfundingError::GateProofRequired)?;
+    require!(
+        token_account.owner == *contributor && token_account.amount > 0,
+        CrowdfundingError::InvalidGateProof
+    );
+
+    if !campaign.gate_is_collection {
+        require!(token_account.mint == campaign.gate_mint, CrowdfundingError::InvalidGateProof);
+        return Ok(());
+    }
+
+    // Collection gates: the held NFT's metadata must name the collection and be verified
+    let metadata = gate_metadata.ok_or(CrowdfundingError::GateProofRequired)?;
+    let (expected_address, _) = Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), token_account.mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    require!(
+        *metadata.owner == TOKEN_METADATA_PROGRAM_ID && metadata.key() == expected_address,
+        CrowdfundingError::InvalidGateProof
+    );
+
+    let data = metadata.try_borrow_data()?;
+    let prefix = MetadataPrefix::deserialize(&mut &data[..])
+        .map_err(|_| error!(CrowdfundingError::InvalidGateProof))?;
+    let in_collection = matches!(
+        prefix.collection,
+        Some(MetadataCollection { verified: true, key }) if key == campaign.gate_mint
+    );
+    require!(
+        prefix.mint == token_account.mint && in_collection,
+        CrowdfundingError::InvalidGateProof
+    );
+
+    Ok(())
+}
+
+/// Moves a queued contribution's escrow to `destination` and closes the escrow account,
+/// returning its rent to the contributor.
+fn release_pending_escrow<'info>(
+    pending: &Account<'info, PendingContribution>,
+    pending_vault: &Account<'info, TokenAccount>,
+    destination: AccountInfo<'info>,
+    contributor: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    vault_bump: u8,
+) -> Result<()> {
+    let pending_key = pending.key();
+    let seeds = &[
+        b"pending_vault",
+        pending_key.as_ref(),
+        &[vault_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: pending_vault.to_account_info(),
+        to: destination,
+        authority: pending_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, pending.amount)?;
+
+    let cpi_accounts = CloseAccount {
+        account: pending_vault.to_account_info(),
+        destination: contributor,
+        authority: pending_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    token::close_account(cpi_ctx)
+}
+
+fn return_pending_contribution(ctx: Context<ResolvePendingContribution>) -> Result<()> {
+    let amount = ctx.accounts.pending_contribution.amount;
+    let balance_before = ctx.accounts.contributor_token_account.amount;
+    ctx.accounts.campaign.pending_contributions -= 1;
+
+    release_pending_escrow(
+        &ctx.accounts.pending_contribution,
+        &ctx.accounts.pending_vault,
+        ctx.accounts.contributor_token_account.to_account_info(),
+        ctx.accounts.contributor.to_account_info(),
+        &ctx.accounts.token_program,
+        ctx.bumps.pending_vault,
+    )?;
+    expect_balance_after_cpi(
+        &mut ctx.accounts.contributor_token_account,
+        balance_before.checked_add(amount),
+    )?;
+
+    emit!(PendingContributionResolved {
+        campaign: ctx.accounts.campaign.key(),
+        contributor: ctx.accounts.contributor.key(),
+        amount,
+        credited: false,
+        decimals: ctx.accounts.campaign.decimals,
+    });
+
+    Ok(())
+}
+
+/// Appends the next entry to the admin audit log. Entries are never closed or rewritten,
+/// so `[b"admin_action", index]` for `0..config.admin_action_count` is the full history.
+fn record_admin_action(
+    admin_action: &mut Account<AdminAction>,
+    config: &mut Account<GlobalConfig>,
+    actor: Pubkey,
+    action: AdminActionKind,
+    target: Pubkey,
+    memo_hash: [u8; 32],
+) -> Result<()> {
+    admin_action.index = config.admin_action_count;
+    admin_action.actor = actor;
+    admin_action.action = action;
+    admin_action.target = target;
+    admin_action.memo_hash = memo_hash;
+    admin_action.recorded_at = Clock::get()?.unix_timestamp;
+
+    config.admin_action_count = config.admin_action_count
+        .checked_add(1)
+        .ok_or(CrowdfundingError::AmountOverflow)?;
+
+    emit!(AdminActionRecorded {
+        index: admin_action.index,
+        actor,
+        action,
+        target,
+    });
+
+    Ok(())
+}
+
+/// Minimum campaign target the admin configured for a mint, or 0 when there is no entry.
+fn mint_minimum_target(info: &AccountInfo) -> Result<u64> {
+    if info.data_is_empty() {
+        return Ok(0);
+    }
+
+    require!(info.owner == &crate::ID, CrowdfundingError::InvalidMintMinimum);
+    let entry = MintTargetMinimum::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    Ok(entry.min_target_amount)
+}
+
+/// Creators with an unexplained failure can't launch again while the platform requires
+/// post-mortems. A creator who never failed has no profile yet.
+fn check_post_mortem_compliance(config: &GlobalConfig, creator_profile: &AccountInfo) -> Result<()> {
+    if !config.require_post_mortem || creator_profile.data_is_empty() {
+        return Ok(());
+    }
+
+    require!(creator_profile.owner == &crate::ID, CrowdfundingError::InvalidCreatorProfile);
+    let profile = CreatorProfile::try_deserialize(&mut &creator_profile.try_borrow_data()?[..])?;
+    require!(!profile.owes_post_mortem(), CrowdfundingError::PostMortemRequired);
+    Ok(())
+}
+
+/// Reads the display symbol (NUL padding stripped, zero-padded to 10 bytes) and the hash of
+/// the metadata URI from the mint's Metaplex metadata account.
+fn read_mint_display(mint: &Pubkey, metadata: &AccountInfo) -> Result<([u8; 10], [u8; 32])> {
+    let (expected_address, _) = Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    require!(
+        *metadata.owner == TOKEN_METADATA_PROGRAM_ID && metadata.key() == expected_address,
+        CrowdfundingError::InvalidMintMetadata
+    );
+
+    let data = metadata.try_borrow_data()?;
+    let prefix = MetadataPrefix::deserialize(&mut &data[..])
+        .map_err(|_| error!(CrowdfundingError::InvalidMintMetadata))?;
+    require!(prefix.mint == *mint, CrowdfundingError::InvalidMintMetadata);
+
+    let symbol = prefix.symbol.trim_end_matches('\0').as_bytes();
+    require!(symbol.len() <= 10, CrowdfundingError::InvalidMintMetadata);
+    let mut padded = [0u8; 10];
+    padded[..symbol.len()].copy_from_slice(symbol);
+
+    let uri = prefix.uri.trim_end_matches('\0');
+    let uri_hash = anchor_lang::solana_program::hash::hash(uri.as_bytes()).to_bytes();
+    Ok((padded, uri_hash))
+}
+
+/// Checks the external account named by a release condition holds the expected bytes.
+fn check_release_condition(
+    condition: Option<&Account<ReleaseCondition>>,
+    condition_account: Option<&AccountInfo>,
+) -> Result<()> {
+    let (condition, info) = match (condition, condition_account) {
+        (Some(condition), Some(info)) => (condition, info),
+        _ => return err!(CrowdfundingError::ReleaseConditionAccountsRequired),
+    };
+    require!(
+        info.key() == condition.account,
+        CrowdfundingError::ReleaseConditionAccountsRequired
+    );
+
+    let start = condition.offset as usize;
+    let end = start + condition.expected.len();
+    let data = info.try_borrow_data()?;
+    require!(
+        *info.owner == condition.owner_program
+            && data.get(start..end) == Some(&condition.expected[..]),
+        CrowdfundingError::ReleaseConditionNotMet
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, description: String)]
+pub struct InitializeCampaign<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Campaign::SIZE,
+        seeds = [b"campaign", creator.key().as_ref(), title.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be empty; an archived campaign's address can never be reused
+    #[account(
+        seeds = [b"summary", campaign.key().as_ref()],
+        bump,
+        constraint = archived_summary.data_is_empty() @ CrowdfundingError::CampaignAddressArchived
+    )]
+    pub archived_summary: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+    
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The admin's minimum target for `mint`; may be uninitialized when none is set
+    #[account(seeds = [b"mint_minimum", mint.key().as_ref()], bump)]
+    pub mint_minimum: UncheckedAccount<'info>,
+
+    /// CHECK: The creator's track record; uninitialized until a campaign of theirs fails
+    #[account(seeds = [b"creator_profile", creator.key().as_ref()], bump)]
+    pub creator_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata of `mint`; owner, address and layout are verified by hand
+    pub mint_metadata: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct Contribute<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+    
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = Contribution::SIZE,
+        seeds = [b"contribution", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"rent_pool", campaign.key().as_ref()],
+        bump
+    )]
+    pub rent_pool: Option<Account<'info, RentPool>>,
+    
+    #[account(
+        mut,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Option<Account<'info, ContributorProfile>>,
+    
+    #[account(seeds = [b"points_config"], bump)]
+    pub points_config: Option<Account<'info, PointsConfig>>,
+    
+    /// CHECK: Only required when `contributor_token_account` isn't owned by the contributor;
+    /// its key must match the token account owner and only its owning program is read
+    pub token_account_owner: Option<UncheckedAccount<'info>>,
+    
+    pub composable_program: Option<Account<'info, ComposableProgram>>,
+    
+    /// Contributor's token account proving membership in the campaign's holder gate
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Metaplex metadata of the gate NFT; owner, address and layout are verified by hand
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Always passed, initialized or not, so an opened sketch sees every backer;
+    /// loaded as a `ContributorSketch` when it exists
+    #[account(mut, seeds = [b"sketch", campaign.key().as_ref()], bump)]
+    pub contributor_sketch: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, on_behalf_of: Pubkey)]
+pub struct ContributeOnBehalf<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = custodian,
+        space = Contribution::SIZE,
+        seeds = [b"contribution", campaign.key().as_ref(), on_behalf_of.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub custodian_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"custodian", custodian.key().as_ref()],
+        bump
+    )]
+    pub custodian_registration: Account<'info, CustodianRegistration>,
+
+    /// CHECK: Always passed, initialized or not, so an opened sketch sees every backer;
+    /// loaded as a `ContributorSketch` when it exists
+    #[account(mut, seeds = [b"sketch", campaign.key().as_ref()], bump)]
+    pub contributor_sketch: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub custodian: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury
+            @ CrowdfundingError::InvalidTreasuryAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+    
+    /// The campaign creator, or a team member holding the withdraw role
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [b"team", campaign.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub team_member: Option<Account<'info, TeamMember>>,
+    
+    /// CHECK: Always passed, initialized or not, so a configured stakers' share can't be skipped;
+    /// loaded as a `RevenueShareConfig` when it exists
+    #[account(mut, seeds = [b"revenue_share"], bump)]
+    pub revenue_share: UncheckedAccount<'info>,
+    
+    /// Required when the revenue share is configured for the campaign's mint
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Umbrella campaign, required when this campaign routes a share to a parent
+    #[account(mut)]
+    pub parent_campaign: Option<Account<'info, Campaign>>,
+    
+    #[account(mut)]
+    pub parent_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Parent creator's payout account, used instead of the vault once the parent has withdrawn
+    #[account(mut)]
+    pub parent_creator_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: The parent's archive summary, or its closed campaign address; only its key,
+    /// owner and emptiness are read, and only to skip a share to a parent that is gone
+    pub closed_parent: Option<UncheckedAccount<'info>>,
+    
+    #[account(seeds = [b"release_condition", campaign.key().as_ref()], bump)]
+    pub release_condition: Option<Account<'info, ReleaseCondition>>,
+    
+    /// CHECK: The external account named by `release_condition`; only its owner and data are read
+    pub condition_account: Option<UncheckedAccount<'info>>,
+    
+    /// Required when the campaign owes its rent sponsor a reimbursement
+    #[account(seeds = [b"rent_pool", campaign.key().as_ref()], bump)]
+    pub rent_pool: Option<Account<'info, RentPool>>,
+    
+    #[account(mut)]
+    pub sponsor_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RefundContribution<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+    
+    #[account(
+        mut,
+        seeds = [b"contribution", campaign.key().as_ref(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contribution.refund_owner()
+            @ CrowdfundingError::InvalidRefundDestination,
+        constraint = contributor_token_account.mint == campaign_vault.mint
+            @ CrowdfundingError::RefundMintMismatch
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FundRentPool<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = RentPool::SIZE,
+        seeds = [b"rent_pool", campaign.key().as_ref()],
+        bump
+    )]
+    pub rent_pool: Account<'info, RentPool>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRentPool<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [b"rent_pool", campaign.key().as_ref()],
+        bump
+    )]
+    pub rent_pool: Account<'info, RentPool>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ArchiveCampaign<'info> {
+    #[account(mut, close = creator)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Must be empty; a sponsor's pool is closed against the live campaign
+    #[account(
+        seeds = [b"rent_pool", campaign.key().as_ref()],
+        bump,
+        constraint = rent_pool.data_is_empty() @ CrowdfundingError::CampaignAccountsOutstanding
+    )]
+    pub rent_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Must be empty; a boost is unstaked against the live campaign
+    #[account(
+        seeds = [b"boost", campaign.key().as_ref()],
+        bump,
+        constraint = boost.data_is_empty() @ CrowdfundingError::CampaignAccountsOutstanding
+    )]
+    pub boost: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = CampaignSummary::SIZE,
+        seeds = [b"summary", campaign.key().as_ref()],
+        bump
+    )]
+    pub summary: Account<'info, CampaignSummary>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = GlobalConfig::SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeCampaign<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdminForceRefund<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", campaign.key().as_ref(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contribution.refund_owner()
+            @ CrowdfundingError::InvalidRefundDestination,
+        constraint = contributor_token_account.mint == campaign_vault.mint
+            @ CrowdfundingError::RefundMintMismatch
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(custodian: Pubkey)]
+pub struct RegisterCustodian<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = CustodianRegistration::SIZE,
+        seeds = [b"custodian", custodian.as_ref()],
+        bump
+    )]
+    pub custodian_registration: Account<'info, CustodianRegistration>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveCustodian<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"custodian", custodian_registration.custodian.as_ref()],
+        bump
+    )]
+    pub custodian_registration: Account<'info, CustodianRegistration>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct RegisterComposableProgram<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ComposableProgram::SIZE,
+        seeds = [b"composable", program_id.as_ref()],
+        bump
+    )]
+    pub composable_program: Account<'info, ComposableProgram>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveComposableProgram<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"composable", composable_program.program_id.as_ref()],
+        bump
+    )]
+    pub composable_program: Account<'info, ComposableProgram>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitSurveyAnswers<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [b"contribution", campaign.key().as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        init_if_needed,
+        payer = backer,
+        space = SurveyCommitment::SIZE,
+        seeds = [b"survey", campaign.key().as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub survey: Account<'info, SurveyCommitment>,
+
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSurvey<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StartProrataRefunds<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTemplate<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CampaignTemplate::SIZE,
+        seeds = [b"template", authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub template: Account<'info, CampaignTemplate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, description: String)]
+pub struct InitializeCampaignFromTemplate<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Campaign::SIZE,
+        seeds = [b"campaign", creator.key().as_ref(), title.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be empty; an archived campaign's address can never be reused
+    #[account(
+        seeds = [b"summary", campaign.key().as_ref()],
+        bump,
+        constraint = archived_summary.data_is_empty() @ CrowdfundingError::CampaignAddressArchived
+    )]
+    pub archived_summary: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub template: Account<'info, CampaignTemplate>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The admin's minimum target for `mint`; may be uninitialized when none is set
+    #[account(seeds = [b"mint_minimum", mint.key().as_ref()], bump)]
+    pub mint_minimum: UncheckedAccount<'info>,
+
+    /// CHECK: The creator's track record; uninitialized until a campaign of theirs fails
+    #[account(seeds = [b"creator_profile", creator.key().as_ref()], bump)]
+    pub creator_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata of `mint`; owner, address and layout are verified by hand
+    pub mint_metadata: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateSeries<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Series::SIZE,
+        seeds = [b"series", creator.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub series: Account<'info, Series>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, description: String)]
+pub struct InitializeNextInSeries<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Campaign::SIZE,
+        seeds = [b"campaign", creator.key().as_ref(), title.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be empty; an archived campaign's address can never be reused
+    #[account(
+        seeds = [b"summary", campaign.key().as_ref()],
+        bump,
+        constraint = archived_summary.data_is_empty() @ CrowdfundingError::CampaignAddressArchived
+    )]
+    pub archived_summary: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub series: Account<'info, Series>,
+
+    pub previous_campaign: Option<Account<'info, Campaign>>,
+
+    /// The previous season's `CampaignSummary`, passed instead once it has been archived
+    pub previous_summary: Option<Account<'info, CampaignSummary>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The admin's minimum target for `mint`; may be uninitialized when none is set
+    #[account(seeds = [b"mint_minimum", mint.key().as_ref()], bump)]
+    pub mint_minimum: UncheckedAccount<'info>,
+
+    /// CHECK: The creator's track record; uninitialized until a campaign of theirs fails
+    #[account(seeds = [b"creator_profile", creator.key().as_ref()], bump)]
+    pub creator_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata of `mint`; owner, address and layout are verified by hand
+    pub mint_metadata: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetCampaignFeeOverride<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRefundAddress<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", campaign.key().as_ref(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    pub contributor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCampaign<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimStalledFunds<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [b"contribution", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    pub contributor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveWithdrawal<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = ApprovalSet::SIZE,
+        seeds = [b"approvals", campaign.key().as_ref()],
+        bump
+    )]
+    pub approval_set: Account<'info, ApprovalSet>,
+
+    #[account(mut)]
+    pub approver: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    pub withdraw: WithdrawFunds<'info>,
+
+    #[account(
+        seeds = [b"approvals", withdraw.campaign.key().as_ref()],
+        bump
+    )]
+    pub approval_set: Account<'info, ApprovalSet>,
+}
+
+#[derive(Accounts)]
+#[instruction(contributor: Pubkey)]
+pub struct SetReputationTier<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ContributorProfile::SIZE,
+        seeds = [b"profile", contributor.as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeCampaign<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SettlementReport::SIZE,
+        seeds = [b"report", campaign.key().as_ref()],
+        bump
+    )]
+    pub settlement_report: Account<'info, SettlementReport>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CreatorProfile::SIZE,
+        seeds = [b"creator_profile", campaign.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateRound<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Round::SIZE,
+        seeds = [b"round", authority.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = round_vault,
+        seeds = [b"round_vault", round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct FundRound<'info> {
+    #[account(mut)]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [b"round_vault", round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct EnrollInRound<'info> {
+    #[account(mut)]
+    pub round: Account<'info, Round>,
+
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = RoundEnrollment::SIZE,
+        seeds = [b"enrollment", round.key().as_ref(), campaign.key().as_ref()],
+        bump
+    )]
+    pub enrollment: Account<'info, RoundEnrollment>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeInRound<'info> {
+    pub contribute: Contribute<'info>,
+
+    #[account(mut)]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", round.key().as_ref(), contribute.campaign.key().as_ref()],
+        bump
+    )]
+    pub enrollment: Account<'info, RoundEnrollment>,
+
+    #[account(
+        init_if_needed,
+        payer = contribute.contributor,
+        space = RoundContribution::SIZE,
+        seeds = [
+            b"round_contribution",
+            round.key().as_ref(),
+            contribute.campaign.key().as_ref(),
+            contribute.contributor.key().as_ref()
+        ],
+        bump
+    )]
+    pub round_contribution: Account<'info, RoundContribution>,
+
+    /// CHECK: The contributor's attestation from the round's weight source; may be uninitialized,
+    /// and is only deserialized after its owner is checked
+    #[account(
+        seeds = [b"trust", round.attestor.as_ref(), contribute.contributor.key().as_ref()],
+        bump
+    )]
+    pub trust_attestation: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRound<'info> {
+    #[account(mut)]
+    pub round: Account<'info, Round>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(contributor: Pubkey)]
+pub struct SetTrustWeight<'info> {
+    #[account(
+        init_if_needed,
+        payer = attestor,
+        space = TrustAttestation::SIZE,
+        seeds = [b"trust", attestor.key().as_ref(), contributor.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, TrustAttestation>,
+
+    #[account(mut)]
+    pub attestor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeRound<'info> {
+    #[account(mut)]
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [b"round_vault", round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRoundRemainder<'info> {
+    pub round: Account<'info, Round>,
+
+    #[account(
+        mut,
+        seeds = [b"round_vault", round.key().as_ref()],
+        bump
+    )]
+    pub round_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct StakeForBoost<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = Boost::SIZE,
+        seeds = [b"boost", campaign.key().as_ref()],
+        bump
+    )]
+    pub boost: Account<'info, Boost>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        token::mint = platform_mint,
+        token::authority = boost_vault,
+        seeds = [b"boost_vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub boost_vault: Account<'info, TokenAccount>,
+
+    #[account(address = config.platform_mint)]
+    pub platform_mint: Account<'info, Mint>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UnstakeBoost<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"boost", campaign.key().as_ref()],
+        bump
+    )]
+    pub boost: Account<'info, Boost>,
+
+    #[account(
+        mut,
+        seeds = [b"boost_vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub boost_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetPointsConfig<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PointsConfig::SIZE,
+        seeds = [b"points_config"],
+        bump
+    )]
+    pub points_config: Account<'info, PointsConfig>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitContributorProfile<'info> {
+    #[account(
+        init,
+        payer = contributor,
+        space = ContributorProfile::SIZE,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemPointsForFeeDiscount<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", creator.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(seeds = [b"points_config"], bump)]
+    pub points_config: Account<'info, PointsConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", contributor.key().as_ref()],
+        bump
+    )]
+    pub contributor_profile: Account<'info, ContributorProfile>,
+
+    #[account(seeds = [b"points_config"], bump)]
+    pub points_config: Account<'info, PointsConfig>,
+
+    pub contributor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AddTeamMember<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = TeamMember::SIZE,
+        seeds = [b"team", campaign.key().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub team_member: Account<'info, TeamMember>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveTeamMember<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"team", campaign.key().as_ref(), team_member.member.as_ref()],
+        bump
+    )]
+    pub team_member: Account<'info, TeamMember>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TeamAction<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The campaign creator, or a team member holding the required role
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"team", campaign.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub team_member: Option<Account<'info, TeamMember>>,
+}
+
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct AcknowledgeBackers<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = AcknowledgmentsList::SIZE,
+        seeds = [b"acknowledgments", campaign.key().as_ref(), &page.to_le_bytes()],
+        bump
+    )]
+    pub acknowledgments: Account<'info, AcknowledgmentsList>,
+
+    #[account(
+        seeds = [
+            b"acknowledgments",
+            campaign.key().as_ref(),
+            &page.saturating_sub(1).to_le_bytes()
+        ],
+        bump
+    )]
+    pub previous_page: Option<Account<'info, AcknowledgmentsList>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(locale: String)]
+pub struct SetLocalizedMetadata<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = LocalizedMetadata::SIZE,
+        seeds = [b"metadata", campaign.key().as_ref(), locale.as_bytes()],
+        bump
+    )]
+    pub localized_metadata: Account<'info, LocalizedMetadata>,
+
+    /// The campaign creator, or a team member holding the edit-metadata role
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"team", campaign.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub team_member: Option<Account<'info, TeamMember>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FollowCampaign<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = follower,
+        space = Follow::SIZE,
+        seeds = [b"follow", campaign.key().as_ref(), follower.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+
+    #[account(mut)]
+    pub follower: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnfollowCampaign<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = follower,
+        seeds = [b"follow", campaign.key().as_ref(), follower.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+
+    #[account(mut)]
+    pub follower: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeBatch<'info> {
+    #[account(mut)]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RepairVaultAuthorities<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRevenueShare<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RevenueShareConfig::SIZE,
+        seeds = [b"revenue_share"],
+        bump
+    )]
+    pub revenue_share: Account<'info, RevenueShareConfig>,
+
+    /// Fees paid in this mint are shared with stakers
+    #[account(
+        init_if_needed,
+        payer = admin,
+        token::mint = reward_mint,
+        token::authority = revenue_vault,
+        seeds = [b"revenue_vault"],
+        bump
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        token::mint = platform_mint,
+        token::authority = stake_vault,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(address = config.platform_mint)]
+    pub platform_mint: Account<'info, Mint>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct StakePlatformTokens<'info> {
+    #[account(mut, seeds = [b"revenue_share"], bump)]
+    pub revenue_share: Account<'info, RevenueShareConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = StakePosition::SIZE,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(mut, seeds = [b"stake_vault"], bump)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnstakePlatformTokens<'info> {
+    #[account(mut, seeds = [b"revenue_share"], bump)]
+    pub revenue_share: Account<'info, RevenueShareConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(mut, seeds = [b"stake_vault"], bump)]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker_token_account: Account<'info, TokenAccount>,
+
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRevenueShare<'info> {
+    #[account(seeds = [b"revenue_share"], bump)]
+    pub revenue_share: Account<'info, RevenueShareConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", staker.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    #[account(mut, seeds = [b"revenue_vault"], bump)]
+    pub revenue_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub staker_token_account: Account<'info, TokenAccount>,
+
+    pub staker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct QueueContribution<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = contributor,
+        space = PendingContribution::SIZE,
+        seeds = [b"pending", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub pending_contribution: Account<'info, PendingContribution>,
+
+    #[account(
+        init,
+        payer = contributor,
+        token::mint = mint,
+        token::authority = pending_vault,
+        seeds = [b"pending_vault", pending_contribution.key().as_ref()],
+        bump
+    )]
+    pub pending_vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"vault", campaign.key().as_ref()], bump)]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(address = campaign_vault.mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ExecutePendingContribution<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = contributor,
+        seeds = [b"pending", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub pending_contribution: Account<'info, PendingContribution>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_vault", pending_contribution.key().as_ref()],
+        bump
+    )]
+    pub pending_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = Contribution::SIZE,
+        seeds = [b"contribution", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Always passed, initialized or not, so an opened sketch sees every backer;
+    /// loaded as a `ContributorSketch` when it exists
+    #[account(mut, seeds = [b"sketch", campaign.key().as_ref()], bump)]
+    pub contributor_sketch: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolvePendingContribution<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = contributor,
+        seeds = [b"pending", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub pending_contribution: Account<'info, PendingContribution>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_vault", pending_contribution.key().as_ref()],
+        bump
+    )]
+    pub pending_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key()
+            @ CrowdfundingError::InvalidRefundDestination,
+        constraint = contributor_token_account.mint == pending_vault.mint
+            @ CrowdfundingError::RefundMintMismatch
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Receives the escrowed rent back; bound to the pending record by its seeds
+    #[account(mut)]
+    pub contributor: UncheckedAccount<'info>,
+
+    /// The contributor (cancel) or the campaign creator (reject)
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ProveBacker<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [b"contribution", campaign.key().as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    pub backer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = new_admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct SetMintMinimumTarget<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MintTargetMinimum::SIZE,
+        seeds = [b"mint_minimum", mint.as_ref()],
+        bump
+    )]
+    pub mint_minimum: Account<'info, MintTargetMinimum>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ComputeHealth<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CampaignAnalytics::SIZE,
+        seeds = [b"analytics", campaign.key().as_ref()],
+        bump
+    )]
+    pub analytics: Account<'info, CampaignAnalytics>,
+
+    #[account(
+        seeds = [b"profile", campaign.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Option<Account<'info, ContributorProfile>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetCampaignVerified<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenContribution<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = contributor,
         space = Contribution::SIZE,
         seeds = [b"contribution", campaign.key().as_ref(), contributor.key().as_ref()],
         bump
     )]
     pub contribution: Account<'info, Contribution>,
-    
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeSequenced<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key()
+            @ CrowdfundingError::TokenOwnerNotAllowlisted
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Always passed, initialized or not, so an opened sketch sees every backer;
+    /// loaded as a `ContributorSketch` when it exists
+    #[account(mut, seeds = [b"sketch", campaign.key().as_ref()], bump)]
+    pub contributor_sketch: UncheckedAccount<'info>,
+
+    pub contributor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeDelegated<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = Contribution::SIZE,
+        seeds = [b"contribution", campaign.key().as_ref(), source_token_account.owner.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// Cold wallet's token account with `operator` approved as delegate
+    #[account(mut)]
+    pub source_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Always passed, initialized or not, so an opened sketch sees every backer;
+    /// loaded as a `ContributorSketch` when it exists
+    #[account(mut, seeds = [b"sketch", campaign.key().as_ref()], bump)]
+    pub contributor_sketch: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitPostMortem<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_profile", campaign.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitContributorSketch<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ContributorSketch::SIZE,
+        seeds = [b"sketch", campaign.key().as_ref()],
+        bump
+    )]
+    pub sketch: Account<'info, ContributorSketch>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReconcileVault<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Treasury or creator token account, as the policy requires; unused for `Backers`
+    #[account(mut)]
+    pub destination_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(variant: u8)]
+pub struct SetVariantMetadata<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = VariantMetadata::SIZE,
+        seeds = [b"variant", campaign.key().as_ref(), &[variant]],
+        bump
+    )]
+    pub variant_metadata: Account<'info, VariantMetadata>,
+
+    /// The campaign creator, or a team member holding the edit-metadata role
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"team", campaign.key().as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub team_member: Option<Account<'info, TeamMember>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetParentCampaign<'info> {
+    #[account(mut)]
+    pub child: Account<'info, Campaign>,
+
+    pub parent: Account<'info, Campaign>,
+
+    #[account(seeds = [b"vault", child.key().as_ref()], bump)]
+    pub child_vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"vault", parent.key().as_ref()], bump)]
+    pub parent_vault: Account<'info, TokenAccount>,
+
+    pub child_creator: Signer<'info>,
+
+    pub parent_creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RollUpChild<'info> {
+    #[account(
+        constraint = child.parent == Some(parent.key()) @ CrowdfundingError::InvalidParentCampaign
+    )]
+    pub child: Account<'info, Campaign>,
+
+    pub parent: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BundleAnalytics::SIZE,
+        seeds = [b"bundle", parent.key().as_ref()],
+        bump
+    )]
+    pub bundle: Account<'info, BundleAnalytics>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BundleMember::SIZE,
+        seeds = [b"bundle_member", parent.key().as_ref(), child.key().as_ref()],
+        bump
+    )]
+    pub bundle_member: Account<'info, BundleMember>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetReleaseCondition<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = ReleaseCondition::SIZE,
+        seeds = [b"release_condition", campaign.key().as_ref()],
+        bump
+    )]
+    pub release_condition: Account<'info, ReleaseCondition>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClearReleaseCondition<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"release_condition", campaign.key().as_ref()],
+        bump
+    )]
+    pub release_condition: Account<'info, ReleaseCondition>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(distribution_id: u32)]
+pub struct CreateDistribution<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Distribution::SIZE,
+        seeds = [b"distribution", campaign.key().as_ref(), distribution_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        init,
+        payer = creator,
+        token::mint = reward_mint,
+        token::authority = distribution_vault,
+        seeds = [b"distribution_vault", distribution.key().as_ref()],
+        bump
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    #[account(mut)]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution.key().as_ref()],
+        bump
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"contribution", distribution.campaign.as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        init,
+        payer = backer,
+        space = DistributionClaim::SIZE,
+        seeds = [b"distribution_claim", distribution.key().as_ref(), backer.key().as_ref()],
+        bump
+    )]
+    pub claim_receipt: Account<'info, DistributionClaim>,
+
     #[account(
         mut,
-        seeds = [b"vault", campaign.key().as_ref()],
+        constraint = backer_token_account.owner == backer.key()
+            && backer_token_account.mint == distribution.reward_mint
+            @ CrowdfundingError::InvalidDistributionDestination
+    )]
+    pub backer_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub backer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDistributionWindow<'info> {
+    #[account(mut, address = distribution.campaign)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut)]
+    pub distribution: Account<'info, Distribution>,
+
+    #[account(
+        mut,
+        seeds = [b"distribution_vault", distribution.key().as_ref()],
+        bump
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == distribution.reward_mint
+            @ CrowdfundingError::InvalidDistributionDestination
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PublishStatus<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = StatusFeed::SIZE,
+        seeds = [b"status", campaign.key().as_ref()],
+        bump
+    )]
+    pub status_feed: Account<'info, StatusFeed>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RotateCreatorKey<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The campaign's pre-registered recovery key
+    pub recovery: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminAction::SIZE,
+        seeds = [b"admin_action", config.admin_action_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_action: Account<'info, AdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DeferRefund<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", campaign.key().as_ref(), contribution.contributor.as_ref()],
         bump
     )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(seeds = [b"vault", campaign.key().as_ref()], bump)]
     pub campaign_vault: Account<'info, TokenAccount>,
-    
+
+    /// CHECK: The refund account that can't receive funds; only inspected, never written
+    pub destination: UncheckedAccount<'info>,
+
+    pub contributor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RetryRefund<'info> {
     #[account(mut)]
-    pub contributor_token_account: Account<'info, TokenAccount>,
-    
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", campaign.key().as_ref(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Deserialized and validated in the handler to tell missing from frozen accounts
     #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
     pub contributor: Signer<'info>,
-    
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey, title: String)]
+pub struct InitializeCampaignFor<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = Campaign::SIZE,
+        seeds = [b"campaign", creator.as_ref(), title.as_bytes()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = operator,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Must be empty; an archived campaign's address can never be reused
+    #[account(
+        seeds = [b"summary", campaign.key().as_ref()],
+        bump,
+        constraint = archived_summary.data_is_empty() @ CrowdfundingError::CampaignAddressArchived
+    )]
+    pub archived_summary: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: The admin's minimum target for `mint`; may be uninitialized when none is set
+    #[account(seeds = [b"mint_minimum", mint.key().as_ref()], bump)]
+    pub mint_minimum: UncheckedAccount<'info>,
+
+    /// The operator's listing of `creator`; campaigns can only be opened for listed grantees
+    #[account(seeds = [b"grant_creator", creator.as_ref()], bump)]
+    pub grant_creator: Account<'info, GrantCreator>,
+
+    /// CHECK: The named creator's track record; uninitialized until a campaign of theirs fails
+    #[account(seeds = [b"creator_profile", creator.as_ref()], bump)]
+    pub creator_profile: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata of `mint`; owner, address and layout are verified by hand
+    pub mint_metadata: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-#[derive(Accounts)]
-pub struct WithdrawFunds<'info> {
-    #[account(mut)]
-    pub campaign: Account<'info, Campaign>,
-    
-    #[account(
-        mut,
-        seeds = [b"vault", campaign.key().as_ref()],
-        bump
-    )]
-    pub campaign_vault: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub creator_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    pub token_program: Program<'info, Token>,
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct ListGrantCreator<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = GrantCreator::SIZE,
+        seeds = [b"grant_creator", creator.as_ref()],
+        bump
+    )]
+    pub grant_creator: Account<'info, GrantCreator>,
+
+    #[account(seeds = [b"config"], bump)]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlistGrantCreator<'info> {
+    #[account(
+        mut,
+        close = operator,
+        seeds = [b"grant_creator", grant_creator.creator.as_ref()],
+        bump
+    )]
+    pub grant_creator: Account<'info, GrantCreator>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RejectCampaign<'info> {
+    #[account(mut, close = operator)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: Account<'info, TokenAccount>,
+
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key()
+            @ CrowdfundingError::InvalidPayoutAccount,
+        constraint = creator_token_account.mint == campaign_vault.mint
+            @ CrowdfundingError::RefundMintMismatch
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Only receives the rent it paid; must be the operator recorded on the campaign
+    #[account(mut, address = campaign.created_by @ CrowdfundingError::UnauthorizedOperator)]
+    pub operator: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[account]
+#[derive(Default)]
+pub struct Campaign {
+    // Fixed-offset header for memcmp filters, see `schema::memcmp`. Keep ahead of the strings.
+    pub creator: Pubkey,           // 32 bytes
+    pub is_successful: bool,       // 1 byte
+    pub is_withdrawn: bool,        // 1 byte
+    pub is_frozen: bool,           // 1 byte
+    pub is_stalled: bool,          // 1 byte
+    pub category: u8,              // 1 byte
+    pub end_day: u32,              // 4 bytes
+    pub end_time: i64,             // 8 bytes
+    pub title: String,             // 4 + 100 bytes
+    pub description: String,       // 4 + 500 bytes
+    pub target_amount: u64,        // 8 bytes
+    pub current_amount: u64,       // 8 bytes
+    pub start_time: i64,           // 8 bytes
+    pub contributors_count: u32,   // 4 bytes
+    pub require_allowlisted_mint: bool, // 1 byte
+    pub survey_closed_at: i64,     // 8 bytes
+    pub grace_period_seconds: u64, // 8 bytes
+    pub grace_max_shortfall_bps: u16, // 2 bytes
+    pub total_refunded: u64,       // 8 bytes
+    pub prorata_numerator: u64,    // 8 bytes
+    pub prorata_denominator: u64,  // 8 bytes
+    pub series: Pubkey,            // 32 bytes
+    pub fee_override_bps: Option<u16>, // 1 + 2 bytes
+    pub inactivity_timeout: u64,   // 8 bytes
+    pub succeeded_at: i64,         // 8 bytes
+    pub approvers: Vec<Pubkey>,    // 4 + 32 * 8 bytes
+    pub approval_threshold: u8,    // 1 byte
+    pub max_per_contributor: u64,  // 8 bytes
+    pub reputation_scaled_cap: bool, // 1 byte
+    pub finalized_at: i64,         // 8 bytes
+    pub featured_weight: u64,      // 8 bytes
+    pub fee_discount_bps: u16,     // 2 bytes
+    pub fee_collected: u64,        // 8 bytes
+    pub matched_amount: u64,       // 8 bytes
+    pub gate_mint: Pubkey,         // 32 bytes
+    pub gate_is_collection: bool,  // 1 byte
+    pub priority_ends_at: i64,     // 8 bytes
+    pub holder_only: bool,         // 1 byte
+    pub streak_bonus_bps: u16,     // 2 bytes
+    pub followers_count: u32,      // 4 bytes
+    pub faq_hash: [u8; 32],        // 32 bytes
+    pub faq_version: u32,          // 4 bytes
+    pub snipe_window_seconds: u32, // 4 bytes
+    pub snipe_extension_seconds: u32, // 4 bytes
+    pub max_total_extension_seconds: u32, // 4 bytes
+    pub total_extended_seconds: u32, // 4 bytes
+    pub cooldown_threshold: u64,   // 8 bytes
+    pub cooldown_seconds: u32,     // 4 bytes
+    pub decimals: u8,              // 1 byte
+    pub content_key_hash: [u8; 32], // 32 bytes
+    pub min_target_amount: u64,    // 8 bytes
+    pub is_verified: bool,         // 1 byte
+    pub fee_waived: bool,          // 1 byte
+    pub post_mortem_hash: [u8; 32], // 32 bytes
+    pub keyword_bloom: [u8; 32],   // 32 bytes
+    pub mint_symbol: [u8; 10],     // 10 bytes
+    pub mint_uri_hash: [u8; 32],   // 32 bytes
+    pub parent: Option<Pubkey>,    // 1 + 32 bytes
+    pub parent_share_bps: u16,     // 2 bytes
+    pub bundle_inflow: u64,        // 8 bytes
+    pub has_release_condition: bool, // 1 byte
+    pub recovery_key: Pubkey,      // 32 bytes
+    pub creator_frozen: bool,      // 1 byte
+    pub deferred_refunds: u64,     // 8 bytes
+    pub mint_authority_active: bool, // 1 byte
+    pub freeze_authority_active: bool, // 1 byte
+    pub max_contributors: u32,     // 4 bytes, 0 = unlimited
+    pub created_by: Pubkey,        // 32 bytes, operator that created it (default if self-created)
+    pub pending_acceptance: bool,  // 1 byte
+    pub approvers_epoch: u32,      // 4 bytes
+    pub rent_reimbursement_per_account: u64, // 8 bytes
+    pub rent_reimbursement_owed: u64, // 8 bytes
+    pub open_distributions: u32,   // 4 bytes, reward drops not yet swept
+    pub pending_contributions: u32, // 4 bytes, cool-down contributions still escrowed
+}
+
+impl Campaign {
+    pub const SIZE: usize = 8 + 32 + 1 + 1 + 1 + 1 + 1 + 4 + 8
+        + 4 + 100 + 4 + 500 + 8 + 8 + 8 + 4 + 1 + 8 + 8 + 2
+        + 8 + 8 + 8 + 32 + 3 + 8 + 8 + 4 + 32 * Self::MAX_APPROVERS + 1 + 8 + 1 + 8
+        + 8 + 2 + 8 + 8 + 32 + 1 + 8 + 1 + 2 + 4 + 32 + 4
+        + 4 + 4 + 4 + 4 + 8 + 4 + 1 + 32 + 8 + 1 + 1 + 32 + 32 + 10 + 32
+        + 33 + 2 + 8 + 1 + 32 + 1 + 8 + 1 + 1 + 4 + 32 + 1 + 4 + 8 + 8 + 4 + 4;
+    pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+    pub const MAX_KEYWORDS: usize = 16;
+    pub const MAX_KEYWORD_LEN: usize = 32;
+    pub const MAX_PARENT_SHARE_BPS: u16 = 5_000;
+    pub const MAX_APPROVERS: usize = 8;
+    pub const MAX_GRACE_PERIOD_SECONDS: u64 = 7 * 24 * 60 * 60;
+    pub const MAX_DURATION_SECONDS: i64 = 365 * 24 * 60 * 60;
+    pub const MAX_SCHEDULE_LEAD_SECONDS: i64 = 90 * 24 * 60 * 60;
+    pub const MAX_SNIPE_WINDOW_SECONDS: u32 = 24 * 60 * 60;
+    pub const MAX_TOTAL_EXTENSION_SECONDS: u32 = 7 * 24 * 60 * 60;
+    pub const MAX_COOLDOWN_SECONDS: u32 = 3 * 24 * 60 * 60;
+
+    /// Late contributions are accepted only while the raise is within the configured shortfall.
+    pub fn in_grace_period(&self, now: i64) -> bool {
+        if self.grace_period_seconds == 0 || self.is_successful || now < self.end_time {
+            return false;
+        }
+        if now >= self.end_time + self.grace_period_seconds as i64 {
+            return false;
+        }
+
+        let shortfall = self.target_amount.saturating_sub(self.current_amount) as u128;
+        shortfall * 10_000 <= self.target_amount as u128 * self.grace_max_shortfall_bps as u128
+    }
+
+    pub fn is_gated(&self, now: i64) -> bool {
+        self.gate_mint != Pubkey::default() && (self.holder_only || now < self.priority_ends_at)
+    }
+
+    /// Economic parameters can only change before the first contribution.
+    pub fn parameters_locked(&self) -> bool {
+        self.contributors_count > 0 || self.current_amount > 0
+    }
+
+    /// The three bloom-filter bits (0..256) a keyword sets, from the SHA-256 of its lowercase form.
+    pub fn keyword_bits(keyword: &str) -> [usize; 3] {
+        let normalized = keyword.to_ascii_lowercase();
+        let digest = anchor_lang::solana_program::hash::hash(normalized.as_bytes()).to_bytes();
+        [digest[0] as usize, digest[1] as usize, digest[2] as usize]
+    }
+
+    /// False means the keyword was definitely not set; true may be a false positive.
+    pub fn may_match_keyword(&self, keyword: &str) -> bool {
+        Self::keyword_bits(keyword)
+            .iter()
+            .all(|bit| self.keyword_bloom[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Sets the deadline and its day bucket together so `end_day` filters never go stale.
+    pub fn set_end_time(&mut self, end_time: i64) {
+        self.end_time = end_time;
+        self.end_day = (end_time.max(0) / Self::SECONDS_PER_DAY) as u32;
+    }
+
+    /// Pushes the deadline back when a contribution lands in the final window,
+    /// up to the campaign's total extension budget. Returns the seconds added.
+    pub fn extend_for_late_contribution(&mut self, now: i64) -> u32 {
+        if self.snipe_window_seconds == 0
+            || now >= self.end_time
+            || now < self.end_time - self.snipe_window_seconds as i64
+        {
+            return 0;
+        }
+
+        let extension = self.snipe_extension_seconds
+            .min(self.max_total_extension_seconds.saturating_sub(self.total_extended_seconds));
+        self.set_end_time(self.end_time + extension as i64);
+        self.total_extended_seconds += extension;
+        extension
+    }
+
+    /// Contributions at or above the threshold must go through the cool-down queue.
+    pub fn requires_cooldown(&self, amount: u64) -> bool {
+        self.cooldown_threshold > 0 && amount >= self.cooldown_threshold
+    }
+
+    pub fn has_started(&self, now: i64) -> bool {
+        now >= self.start_time
+    }
+
+    pub fn has_ended(&self, now: i64) -> bool {
+        now >= self.end_time && !self.in_grace_period(now)
+    }
+
+    pub fn settlement_outcome(&self) -> types::SettlementOutcome {
+        if self.is_stalled {
+            types::SettlementOutcome::Stalled
+        } else if self.is_successful {
+            types::SettlementOutcome::Succeeded
+        } else {
+            types::SettlementOutcome::Failed
+        }
+    }
+
+    /// Settled means funds were withdrawn, or the campaign failed and is open for refunds.
+    pub fn is_settled(&self, now: i64) -> bool {
+        self.is_withdrawn || self.is_stalled || (self.has_ended(now) && !self.is_successful)
+    }
+
+    /// Rejects contributions that would push a backer past `max_per_contributor * multiplier`.
+    pub fn check_contributor_cap(&self, contributed: u64, amount: u64, multiplier: u64) -> Result<()> {
+        if self.max_per_contributor == 0 {
+            return Ok(());
+        }
+
+        let cap = self.max_per_contributor.saturating_mul(multiplier);
+        let new_total = contributed
+            .checked_add(amount)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        require!(new_total <= cap, CrowdfundingError::ContributorCapExceeded);
+
+        Ok(())
+    }
+
+    pub fn outstanding_contributions(&self) -> u64 {
+        self.current_amount.saturating_sub(self.total_refunded)
+    }
+
+    /// What the vault should hold by the books: unrefunded contributions, matching funds,
+    /// shares routed up from child campaigns and deferred refunds, or nothing once withdrawn.
+    pub fn tracked_vault_balance(&self) -> u64 {
+        if self.is_withdrawn {
+            return 0;
+        }
+        self.outstanding_contributions()
+            .saturating_add(self.matched_amount)
+            .saturating_add(self.bundle_inflow)
+            .saturating_add(self.deferred_refunds)
+    }
+
+    /// Refund owed for a contribution: scaled by the fixed pro-rata ratio once it is set.
+    pub fn refund_amount_for(&self, contributed: u64, vault_balance: u64) -> Result<u64> {
+        if self.prorata_denominator == 0 {
+            // Deferred refunds are already owed; they don't back the remaining contributions
+            require!(
+                vault_balance.saturating_sub(self.deferred_refunds) >= self.outstanding_contributions(),
+                CrowdfundingError::RefundsUndercollateralized
+            );
+            return Ok(contributed);
+        }
+
+        let scaled = contributed as u128 * self.prorata_numerator as u128
+            / self.prorata_denominator as u128;
+        Ok(scaled as u64)
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct Contribution {
+    pub contributor: Pubkey,       // 32 bytes
+    pub campaign: Pubkey,          // 32 bytes
+    pub amount: u64,               // 8 bytes
+    pub custodian: Pubkey,         // 32 bytes
+    pub identity_hash: [u8; 32],   // 32 bytes
+    pub refund_address: Pubkey,    // 32 bytes
+    pub recent_order_ids: [[u8; 16]; 4], // 16 * 4 bytes
+    pub next_order_slot: u8,       // 1 byte
+    pub last_contribution_day: i64, // 8 bytes
+    pub streak_days: u16,          // 2 bytes
+    pub streak_multiplier_bps: u16, // 2 bytes
+    pub funding_reference: Pubkey, // 32 bytes
+    pub terms_hash: [u8; 32],      // 32 bytes
+    pub terms_version: u32,        // 4 bytes
+    pub backer_index: u32,         // 4 bytes
+    pub seq: u64,                  // 8 bytes
+    pub deferred_refund: u64,      // 8 bytes
+}
+
+impl Contribution {
+    pub const ORDER_ID_WINDOW: usize = 4;
+    pub const MAX_STREAK_MULTIPLIER_BPS: u16 = 20_000;
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 32 + 32 + 32 + 16 * Self::ORDER_ID_WINDOW + 1
+        + 8 + 2 + 2 + 32 + 32 + 4 + 4 + 8 + 8;
+
+    /// Extends the streak on a contribution the day after the last one, restarts it after a gap.
+    /// Each day past the first adds `bonus_bps` to the reward multiplier, up to the cap.
+    pub fn record_streak_day(&mut self, now: i64, bonus_bps: u16) {
+        let day = now / (24 * 60 * 60);
+        if self.streak_days > 0 && day == self.last_contribution_day {
+            return;
+        }
+
+        self.streak_days = if self.streak_days > 0 && day == self.last_contribution_day + 1 {
+            self.streak_days.saturating_add(1)
+        } else {
+            1
+        };
+        self.last_contribution_day = day;
+
+        let bonus = (self.streak_days as u32 - 1) * bonus_bps as u32;
+        self.streak_multiplier_bps =
+            (10_000 + bonus).min(Self::MAX_STREAK_MULTIPLIER_BPS as u32) as u16;
+    }
+
+    /// Remembers the last few client order ids in a ring buffer and rejects repeats.
+    pub fn record_client_order_id(&mut self, client_order_id: [u8; 16]) -> Result<()> {
+        require!(client_order_id != [0u8; 16], CrowdfundingError::InvalidClientOrderId);
+        require!(
+            !self.recent_order_ids.contains(&client_order_id),
+            CrowdfundingError::DuplicateClientOrderId
+        );
+
+        let slot = self.next_order_slot as usize % Self::ORDER_ID_WINDOW;
+        self.recent_order_ids[slot] = client_order_id;
+        self.next_order_slot = ((slot + 1) % Self::ORDER_ID_WINDOW) as u8;
+        Ok(())
+    }
+
+    /// Key allowed to claim refunds: the custodian for omnibus records, otherwise the contributor.
+    pub fn refund_authority(&self) -> Pubkey {
+        if self.custodian == Pubkey::default() {
+            self.contributor
+        } else {
+            self.custodian
+        }
+    }
+
+    /// Owner the refund token account must belong to: the alternate address if one was set.
+    pub fn refund_owner(&self) -> Pubkey {
+        if self.refund_address == Pubkey::default() {
+            self.refund_authority()
+        } else {
+            self.refund_address
+        }
+    }
+}
+
+#[account]
+pub struct CustodianRegistration {
+    pub custodian: Pubkey,         // 32 bytes
+    pub approved_at: i64,          // 8 bytes
+}
+
+impl CustodianRegistration {
+    pub const SIZE: usize = 8 + 32 + 8;
+}
+
+/// A program whose PDAs may fund contributions from token accounts they own.
+#[account]
+pub struct ComposableProgram {
+    pub program_id: Pubkey,        // 32 bytes
+    pub approved_at: i64,          // 8 bytes
+}
+
+impl ComposableProgram {
+    pub const SIZE: usize = 8 + 32 + 8;
+}
+
+#[account]
+#[derive(Default)]
+pub struct GlobalConfig {
+    pub admin: Pubkey,             // 32 bytes
+    pub guardian: Pubkey,          // 32 bytes
+    pub mint_allowlist: Vec<Pubkey>, // 4 + 32 * 16 bytes
+    pub treasury: Pubkey,          // 32 bytes
+    pub fee_bps: u16,              // 2 bytes
+    pub platform_mint: Pubkey,     // 32 bytes
+    pub admin_action_count: u64,   // 8 bytes
+    pub pending_admin: Pubkey,     // 32 bytes
+    pub admin_transfer_available_at: i64, // 8 bytes
+    pub timelock_seconds: i64,     // 8 bytes
+    pub pending_fee_bps: u16,      // 2 bytes
+    pub pending_fee_effective_at: i64, // 8 bytes
+    pub charity_fee_waiver: bool,  // 1 byte
+    pub charity_category: u8,      // 1 byte
+    pub require_post_mortem: bool, // 1 byte
+    pub surplus_policy: SurplusPolicy, // 1 byte
+    pub forbid_mint_authorities: bool, // 1 byte
+    pub campaign_operator: Pubkey, // 32 bytes
+}
+
+impl GlobalConfig {
+    pub const MAX_ALLOWED_MINTS: usize = 16;
+    pub const MAX_FEE_BPS: u16 = 1_000;
+    pub const MIN_TIMELOCK_SECONDS: i64 = 24 * 60 * 60;
+    pub const MAX_TIMELOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+    pub const SIZE: usize = 8 + 32 + 32 + 4 + 32 * Self::MAX_ALLOWED_MINTS + 32 + 2 + 32 + 8
+        + 32 + 8 + 8 + 2 + 8 + 1 + 1 + 1 + 1 + 1 + 32;
+
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        self.admin == *key || self.guardian == *key
+    }
+
+    /// Verified campaigns in the charity category pay no platform fee when the waiver is on.
+    pub fn waives_fee_for(&self, campaign: &Campaign) -> bool {
+        self.charity_fee_waiver && campaign.is_verified && campaign.category == self.charity_category
+    }
+
+    /// The platform fee in force at `now`, including a scheduled increase once it matures.
+    pub fn effective_fee_bps(&self, now: i64) -> u16 {
+        if self.pending_fee_effective_at != 0 && now >= self.pending_fee_effective_at {
+            self.pending_fee_bps
+        } else {
+            self.fee_bps
+        }
+    }
+}
+
+/// Where `reconcile_vault` sends tokens that reached a vault outside any contribution.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SurplusPolicy {
+    #[default]
+    Treasury,
+    Creator,
+    /// Shared pro rata through refunds; only once refunds are open
+    Backers,
+}
+
+#[account]
+pub struct RentPool {
+    pub campaign: Pubkey,          // 32 bytes
+    pub sponsor: Pubkey,           // 32 bytes
+    pub accounts_sponsored: u32,   // 4 bytes
+    pub lamports_sponsored: u64,   // 8 bytes
+    pub min_contribution: u64,     // 8 bytes
+}
+
+impl RentPool {
+    pub const SIZE: usize = 8 + 32 + 32 + 4 + 8 + 8;
+}
+
+#[account]
+pub struct CampaignSummary {
+    pub campaign: Pubkey,          // 32 bytes
+    pub creator: Pubkey,           // 32 bytes
+    pub target_amount: u64,        // 8 bytes
+    pub raised_amount: u64,        // 8 bytes
+    pub contributors_count: u32,   // 4 bytes
+    pub is_successful: bool,       // 1 byte
+    pub start_time: i64,           // 8 bytes
+    pub end_time: i64,             // 8 bytes
+    pub archived_at: i64,          // 8 bytes
+}
+
+impl CampaignSummary {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 4 + 1 + 8 + 8 + 8;
+}
+
+#[account]
+pub struct SurveyCommitment {
+    pub campaign: Pubkey,          // 32 bytes
+    pub backer: Pubkey,            // 32 bytes
+    pub answers_hash: [u8; 32],    // 32 bytes
+    pub committed_at: i64,         // 8 bytes
+}
+
+impl SurveyCommitment {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CampaignParams {
+    pub target_amount: u64,        // 8 bytes
+    pub duration_days: u64,        // 8 bytes
+    pub require_allowlisted_mint: bool, // 1 byte
+    pub grace_period_seconds: u64, // 8 bytes
+    pub grace_max_shortfall_bps: u16, // 2 bytes
+    pub max_contributors: u32,     // 4 bytes, 0 = unlimited
+}
+
+impl CampaignParams {
+    pub const SIZE: usize = 8 + 8 + 1 + 8 + 2 + 4;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.target_amount > 0, CrowdfundingError::InvalidTargetAmount);
+        require!(
+            self.duration_days > 0 && self.duration_days <= 365,
+            CrowdfundingError::InvalidDuration
+        );
+        require!(
+            self.grace_period_seconds <= Campaign::MAX_GRACE_PERIOD_SECONDS
+                && self.grace_max_shortfall_bps <= 10_000,
+            CrowdfundingError::InvalidGracePeriod
+        );
+        Ok(())
+    }
+}
+
+#[account]
+pub struct CampaignTemplate {
+    pub authority: Pubkey,         // 32 bytes
+    pub name: String,              // 4 + 32 bytes
+    pub params: CampaignParams,    // CampaignParams::SIZE bytes
+    pub campaigns_created: u32,    // 4 bytes
+}
+
+impl CampaignTemplate {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const SIZE: usize = 8 + 32 + 4 + Self::MAX_NAME_LEN + CampaignParams::SIZE + 4;
+}
+
+#[account]
+pub struct Series {
+    pub creator: Pubkey,           // 32 bytes
+    pub name: String,              // 4 + 32 bytes
+    pub season: u32,               // 4 bytes
+    pub cumulative_raised: u64,    // 8 bytes
+    pub current_campaign: Pubkey,  // 32 bytes
+}
+
+impl Series {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const SIZE: usize = 8 + 32 + 4 + Self::MAX_NAME_LEN + 4 + 8 + 32;
+}
+
+#[account]
+pub struct ApprovalSet {
+    pub campaign: Pubkey,          // 32 bytes
+    pub approvals_bitmap: u8,      // 1 byte
+    pub epoch: u32,                // 4 bytes, Campaign::approvers_epoch the bits refer to
+}
+
+impl ApprovalSet {
+    pub const SIZE: usize = 8 + 32 + 1 + 4;
+
+    pub fn approvals(&self) -> u32 {
+        self.approvals_bitmap.count_ones()
+    }
+}
+
+#[account]
+pub struct ContributorProfile {
+    pub contributor: Pubkey,       // 32 bytes
+    pub reputation_tier: u8,       // 1 byte
+    pub points: u64,               // 8 bytes
+}
+
+impl ContributorProfile {
+    pub const MAX_REPUTATION_TIER: u8 = 4;
+    pub const SIZE: usize = 8 + 32 + 1 + 8;
+
+    /// Tier 0 gets the base cap; each tier above adds another multiple of it.
+    pub fn cap_multiplier(&self) -> u64 {
+        self.reputation_tier as u64 + 1
+    }
+}
+
+/// A matching round shared by many campaigns, funded by one or more sponsors.
+#[account]
+pub struct Round {
+    pub authority: Pubkey,         // 32 bytes
+    pub name: String,              // 4 + 32 bytes
+    pub mint: Pubkey,              // 32 bytes
+    pub start_time: i64,           // 8 bytes
+    pub end_time: i64,             // 8 bytes
+    pub eligible_category: u8,     // 1 byte
+    pub max_match_per_campaign: u64, // 8 bytes
+    pub matching_pool: u64,        // 8 bytes
+    pub total_weight: u128,        // 16 bytes
+    pub enrolled_count: u32,       // 4 bytes
+    pub settled_count: u32,        // 4 bytes
+    pub finalized_at: i64,         // 8 bytes
+    pub attestor: Pubkey,          // 32 bytes
+    pub default_weight_bps: u16,   // 2 bytes
+}
+
+impl Round {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const FULL_WEIGHT_BPS: u16 = 10_000;
+    pub const SIZE: usize = 8 + 32 + 4 + Self::MAX_NAME_LEN + 32 + 8 + 8 + 1 + 8 + 8 + 16 + 4 + 4 + 8
+        + 32 + 2;
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.start_time && now < self.end_time
+    }
+
+    /// A campaign's share of the pool, proportional to its quadratic weight and capped per project.
+    pub fn match_for(&self, weight: u128) -> u64 {
+        if self.total_weight == 0 {
+            return 0;
+        }
+        let share = (self.matching_pool as u128 * weight / self.total_weight) as u64;
+        if self.max_match_per_campaign > 0 {
+            share.min(self.max_match_per_campaign)
+        } else {
+            share
+        }
+    }
+}
+
+#[account]
+pub struct RoundEnrollment {
+    pub round: Pubkey,             // 32 bytes
+    pub campaign: Pubkey,          // 32 bytes
+    pub raised_in_round: u64,      // 8 bytes
+    pub sqrt_sum: u64,             // 8 bytes
+    pub match_amount: u64,         // 8 bytes
+    pub is_settled: bool,          // 1 byte
+}
+
+impl RoundEnrollment {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    /// Quadratic funding weight: the square of the sum of square roots of each backer's total.
+    pub fn weight(&self) -> u128 {
+        self.sqrt_sum as u128 * self.sqrt_sum as u128
+    }
+}
+
+#[account]
+pub struct RoundContribution {
+    pub round: Pubkey,             // 32 bytes
+    pub campaign: Pubkey,          // 32 bytes
+    pub contributor: Pubkey,       // 32 bytes
+    pub amount: u64,               // 8 bytes
+    pub weight_bps: u16,           // 2 bytes
+}
+
+impl RoundContribution {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 2;
+
+    /// This backer's term in the campaign's sum of square roots, scaled by its trust weight.
+    pub fn sqrt_term(&self) -> u64 {
+        (isqrt(self.amount) as u128 * self.weight_bps as u128 / Round::FULL_WEIGHT_BPS as u128) as u64
+    }
+}
+
+/// A trust score for one contributor, published by an attestor (e.g. a passport-score bridge).
+#[account]
+pub struct TrustAttestation {
+    pub attestor: Pubkey,          // 32 bytes
+    pub contributor: Pubkey,       // 32 bytes
+    pub weight_bps: u16,           // 2 bytes
+    pub updated_at: i64,           // 8 bytes
+}
+
+impl TrustAttestation {
+    pub const SIZE: usize = 8 + 32 + 32 + 2 + 8;
+}
+
+/// Platform tokens a creator has locked to feature their campaign.
+#[account]
+pub struct Boost {
+    pub campaign: Pubkey,          // 32 bytes
+    pub amount: u64,               // 8 bytes
+    pub staked_at: i64,            // 8 bytes
+}
+
+impl Boost {
+    pub const SIZE: usize = 8 + 32 + 8 + 8;
+}
+
+/// Platform-wide loyalty program settings. Points are non-transferable: they only live on profiles.
+#[account]
+pub struct PointsConfig {
+    pub accrual_bps: u16,          // 2 bytes
+    pub points_per_discount_bps: u64, // 8 bytes
+    pub max_fee_discount_bps: u16, // 2 bytes
+    pub badge_cost: u64,           // 8 bytes
+}
+
+impl PointsConfig {
+    pub const SIZE: usize = 8 + 2 + 8 + 2 + 8;
+
+    pub fn points_for(&self, amount: u64) -> u64 {
+        (amount as u128 * self.accrual_bps as u128 / 10_000) as u64
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum PointsReward {
+    FeeDiscount { campaign: Pubkey, discount_bps: u16 },
+    Badge { badge_id: u16 },
+}
+
+/// A co-creator of a campaign and the actions they may take on the creator's behalf.
+#[account]
+pub struct TeamMember {
+    pub campaign: Pubkey,          // 32 bytes
+    pub member: Pubkey,            // 32 bytes
+    pub roles: u8,                 // 1 byte
+    pub added_at: i64,             // 8 bytes
+}
+
+impl TeamMember {
+    pub const CAN_POST_UPDATES: u8 = 1 << 0;
+    pub const CAN_WITHDRAW: u8 = 1 << 1;
+    pub const CAN_EDIT_METADATA: u8 = 1 << 2;
+    pub const ALL_ROLES: u8 = Self::CAN_POST_UPDATES | Self::CAN_WITHDRAW | Self::CAN_EDIT_METADATA;
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + 8;
+
+    pub fn has_role(&self, role: u8) -> bool {
+        self.roles & role == role
+    }
+}
+
+/// One page of a campaign's permanent thank-you wall: backer pubkeys or hashes of names.
+#[account]
+pub struct AcknowledgmentsList {
+    pub campaign: Pubkey,          // 32 bytes
+    pub page: u32,                 // 4 bytes
+    pub entries: Vec<[u8; 32]>,    // 4 + 32 * 32 bytes
+}
+
+impl AcknowledgmentsList {
+    pub const MAX_ENTRIES: usize = 32;
+    pub const SIZE: usize = 8 + 32 + 4 + 4 + 32 * Self::MAX_ENTRIES;
+
+    pub fn is_full(&self) -> bool {
+        self.entries.len() == Self::MAX_ENTRIES
+    }
+}
+
+/// Per-locale pointer to translated description and images, kept off the campaign account.
+#[account]
+pub struct LocalizedMetadata {
+    pub campaign: Pubkey,          // 32 bytes
+    pub locale: String,            // 4 + 16 bytes (BCP 47 tag, e.g. "pt-BR")
+    pub uri_hash: [u8; 32],        // 32 bytes
+    pub updated_at: i64,           // 8 bytes
+}
+
+impl LocalizedMetadata {
+    pub const MAX_LOCALE_LEN: usize = 16;
+    pub const SIZE: usize = 8 + 32 + 4 + Self::MAX_LOCALE_LEN + 32 + 8;
+}
+
+/// Canonical end-of-campaign record for auditors and backers, refreshed by `finalize_campaign`.
+#[account]
+pub struct SettlementReport {
+    pub campaign: Pubkey,          // 32 bytes
+    pub outcome: types::SettlementOutcome, // 1 byte
+    pub target_amount: u64,        // 8 bytes
+    pub raised_amount: u64,        // 8 bytes
+    pub fee_collected: u64,        // 8 bytes
+    pub matched_amount: u64,       // 8 bytes
+    pub refunded_amount: u64,      // 8 bytes
+    pub contributors_count: u32,   // 4 bytes
+    pub start_time: i64,           // 8 bytes
+    pub end_time: i64,             // 8 bytes
+    pub succeeded_at: i64,         // 8 bytes
+    pub finalized_at: i64,         // 8 bytes
+    pub updated_at: i64,           // 8 bytes
+    pub fee_waived: bool,          // 1 byte
+}
+
+impl SettlementReport {
+    pub const SIZE: usize = 8 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 4 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+#[account]
+pub struct Follow {
+    pub campaign: Pubkey,          // 32 bytes
+    pub follower: Pubkey,          // 32 bytes
+    pub followed_at: i64,          // 8 bytes
+}
+
+impl Follow {
+    pub const SIZE: usize = 8 + 32 + 32 + 8;
+}
+
+/// One leg of `contribute_batch`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchContribution {
+    pub campaign: Pubkey,
+    pub amount: u64,
+}
+
+impl BatchContribution {
+    pub const MAX_ENTRIES: usize = 8;
+}
+
+/// Routes a share of platform fees to platform-token stakers. Rewards accrue through a
+/// per-share accumulator, so a fee credit never has to touch individual stake positions.
+#[account]
+pub struct RevenueShareConfig {
+    pub reward_mint: Pubkey,       // 32 bytes
+    pub share_bps: u16,            // 2 bytes
+    pub total_staked: u64,         // 8 bytes
+    pub acc_revenue_per_share: u128, // 16 bytes
+}
+
+impl RevenueShareConfig {
+    pub const PRECISION: u128 = 1_000_000_000_000;
+    pub const SIZE: usize = 8 + 32 + 2 + 8 + 16;
+
+    pub fn record_revenue(&mut self, amount: u64) -> Result<()> {
+        let increment = amount as u128 * Self::PRECISION / self.total_staked as u128;
+        self.acc_revenue_per_share = self.acc_revenue_per_share
+            .checked_add(increment)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct StakePosition {
+    pub staker: Pubkey,            // 32 bytes
+    pub amount: u64,               // 8 bytes
+    pub revenue_debt: u128,        // 16 bytes
+    pub pending_revenue: u64,      // 8 bytes
+}
+
+impl StakePosition {
+    pub const SIZE: usize = 8 + 32 + 8 + 16 + 8;
+
+    /// Moves revenue earned since the last stake change into `pending_revenue`
+    pub fn settle(&mut self, acc_revenue_per_share: u128) -> Result<()> {
+        let accrued = self.amount as u128 * acc_revenue_per_share / RevenueShareConfig::PRECISION;
+        let earned = accrued.saturating_sub(self.revenue_debt) as u64;
+        self.pending_revenue = self.pending_revenue
+            .checked_add(earned)
+            .ok_or(CrowdfundingError::AmountOverflow)?;
+        Ok(())
+    }
+
+    pub fn reset_debt(&mut self, acc_revenue_per_share: u128) {
+        self.revenue_debt =
+            self.amount as u128 * acc_revenue_per_share / RevenueShareConfig::PRECISION;
+    }
+}
+
+/// A large contribution waiting out the campaign's cool-down before it is credited.
+#[account]
+pub struct PendingContribution {
+    pub campaign: Pubkey,          // 32 bytes
+    pub contributor: Pubkey,       // 32 bytes
+    pub amount: u64,               // 8 bytes
+    pub queued_at: i64,            // 8 bytes
+    pub release_at: i64,           // 8 bytes
+}
+
+impl PendingContribution {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminActionKind {
+    AddAllowedMint,
+    RemoveAllowedMint,
+    RegisterCustodian,
+    RemoveCustodian,
+    RegisterComposableProgram,
+    RemoveComposableProgram,
+    SetGuardian,
+    FreezeCampaign,
+    ForceRefund,
+    SetFeeSchedule,
+    SetCampaignFeeOverride,
+    SetReputationTier,
+    SetPlatformMint,
+    SetPointsConfig,
+    RepairVaultAuthorities,
+    SetRevenueShare,
+    SetTimelock,
+    ProposeAdmin,
+    CancelAdminProposal,
+    AcceptAdmin,
+    SetMintMinimumTarget,
+    SetCampaignVerified,
+    SetCharityFeeWaiver,
+    SetPostMortemRequired,
+    SetSurplusPolicy,
+    RotateCreatorKey,
+    SetMintAuthorityPolicy,
+    SetCampaignOperator,
+}
+
+/// One entry in the append-only log of admin and guardian actions.
+#[account]
+pub struct AdminAction {
+    pub index: u64,                // 8 bytes
+    pub actor: Pubkey,             // 32 bytes
+    pub action: AdminActionKind,   // 1 byte
+    pub target: Pubkey,            // 32 bytes
+    pub memo_hash: [u8; 32],       // 32 bytes
+    pub recorded_at: i64,          // 8 bytes
+}
+
+impl AdminAction {
+    pub const SIZE: usize = 8 + 8 + 32 + 1 + 32 + 32 + 8;
+}
+
+/// Admin-managed floor on campaign targets for one mint, so targets stay meaningful
+/// across mints with very different unit values.
+#[account]
+pub struct MintTargetMinimum {
+    pub mint: Pubkey,              // 32 bytes
+    pub min_target_amount: u64,    // 8 bytes
+}
+
+impl MintTargetMinimum {
+    pub const SIZE: usize = 8 + 32 + 8;
+}
+
+/// Canonical ranking signals for a campaign, refreshed by anyone through `compute_health`.
+#[account]
+pub struct CampaignAnalytics {
+    pub campaign: Pubkey,          // 32 bytes
+    pub health_score: u16,         // 2 bytes
+    pub velocity_bps: u16,         // 2 bytes
+    pub diversity_bps: u16,        // 2 bytes
+    pub reputation_bps: u16,       // 2 bytes
+    pub computed_at: i64,          // 8 bytes
+}
+
+impl CampaignAnalytics {
+    pub const SIZE: usize = 8 + 32 + 2 + 2 + 2 + 2 + 8;
+    pub const VELOCITY_WEIGHT: u32 = 50;
+    pub const DIVERSITY_WEIGHT: u32 = 30;
+    pub const REPUTATION_WEIGHT: u32 = 20;
+
+    /// Scores are in basis points (0..=10000):
+    /// - velocity: funding progress relative to the share of the campaign window elapsed,
+    ///   capped once the campaign is on pace
+    /// - diversity: 1 - 1/backers, so a single backer scores 0
+    /// - reputation: the creator's reputation tier relative to the maximum
+    pub fn record_health(&mut self, campaign: &Campaign, reputation_tier: Option<u8>, now: i64) -> u16 {
+        let progress_bps = (campaign.current_amount as u128 * 10_000
+            / campaign.target_amount.max(1) as u128)
+            .min(10_000) as u32;
+
+        let duration = (campaign.end_time - campaign.start_time).max(1) as u128;
+        let elapsed = (now - campaign.start_time).clamp(0, campaign.end_time - campaign.start_time) as u128;
+        let elapsed_bps = (elapsed * 10_000 / duration).max(1) as u32;
+
+        self.velocity_bps = (progress_bps as u64 * 10_000 / elapsed_bps as u64).min(10_000) as u16;
+        self.diversity_bps = match campaign.contributors_count {
+            0 => 0,
+            count => (10_000 - 10_000 / count) as u16,
+        };
+        self.reputation_bps = reputation_tier
+            .map(|tier| tier as u32 * 10_000 / ContributorProfile::MAX_REPUTATION_TIER as u32)
+            .unwrap_or(0) as u16;
+
+        self.health_score = ((self.velocity_bps as u32 * Self::VELOCITY_WEIGHT
+            + self.diversity_bps as u32 * Self::DIVERSITY_WEIGHT
+            + self.reputation_bps as u32 * Self::REPUTATION_WEIGHT)
+            / 100) as u16;
+        self.computed_at = now;
+        self.health_score
+    }
+}
+
+/// Per-creator track record, written when a campaign is finalized as a failure.
+#[account]
+pub struct CreatorProfile {
+    pub creator: Pubkey,           // 32 bytes
+    pub campaigns_failed: u32,     // 4 bytes
+    pub post_mortems_posted: u32,  // 4 bytes
+}
+
+impl CreatorProfile {
+    pub const SIZE: usize = 8 + 32 + 4 + 4;
+
+    pub fn owes_post_mortem(&self) -> bool {
+        self.post_mortems_posted < self.campaigns_failed
+    }
+}
+
+/// HyperLogLog registers over contributor keys. Unlike `contributors_count`, the estimate
+/// survives closed or pruned Contribution accounts, and repeat deposits never inflate it.
+#[account]
+pub struct ContributorSketch {
+    pub campaign: Pubkey,          // 32 bytes
+    pub registers: [u8; 128],      // 128 bytes
+}
+
+impl ContributorSketch {
+    pub const REGISTERS: usize = 128;
+    pub const SIZE: usize = 8 + 32 + Self::REGISTERS;
+
+    pub fn observe(&mut self, contributor: &Pubkey) {
+        let digest = anchor_lang::solana_program::hash::hash(contributor.as_ref()).to_bytes();
+        let index = (digest[0] >> 1) as usize;
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&digest[1..9]);
+        let rank = (u64::from_le_bytes(word).leading_zeros() + 1) as u8;
+
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Approximate number of distinct contributors (about 9% standard error).
+    pub fn estimate(&self) -> u64 {
+        let m = Self::REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let raw = alpha * m * m / sum;
+
+        // Small-range correction: linear counting while registers are still empty
+        let empty = self.registers.iter().filter(|r| **r == 0).count();
+        if raw <= 2.5 * m && empty > 0 {
+            (m * (m / empty as f64).ln()) as u64
+        } else {
+            raw as u64
+        }
+    }
+}
+
+/// One A/B metadata variant of a campaign, at `[b"variant", campaign, variant]`.
+#[account]
+pub struct VariantMetadata {
+    pub campaign: Pubkey,          // 32 bytes
+    pub variant: u8,               // 1 byte (0 = A, 1 = B)
+    pub uri_hash: [u8; 32],        // 32 bytes
+    pub updated_at: i64,           // 8 bytes
+}
+
+impl VariantMetadata {
+    pub const MAX_VARIANTS: u8 = 2;
+    pub const SIZE: usize = 8 + 32 + 1 + 32 + 8;
+}
+
+/// Rolled-up totals of an umbrella campaign's children, refreshed by `roll_up_child`.
+#[account]
+pub struct BundleAnalytics {
+    pub parent: Pubkey,            // 32 bytes
+    pub children_count: u32,       // 4 bytes
+    pub total_raised: u64,         // 8 bytes
+    pub total_backers: u64,        // 8 bytes
+    pub updated_at: i64,           // 8 bytes
+}
+
+impl BundleAnalytics {
+    pub const SIZE: usize = 8 + 32 + 4 + 8 + 8 + 8;
+}
+
+/// The child's figures as of its last roll-up, so each crank only adds the difference.
+#[account]
+pub struct BundleMember {
+    pub parent: Pubkey,            // 32 bytes
+    pub child: Pubkey,             // 32 bytes
+    pub last_raised: u64,          // 8 bytes
+    pub last_backers: u32,         // 4 bytes
+}
+
+impl BundleMember {
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 4;
+}
+
+/// External state the campaign's withdrawal waits on, e.g. a flag in another protocol's registry.
+#[account]
+pub struct ReleaseCondition {
+    pub campaign: Pubkey,          // 32 bytes
+    pub account: Pubkey,           // 32 bytes
+    pub owner_program: Pubkey,     // 32 bytes
+    pub offset: u32,               // 4 bytes
+    pub expected: Vec<u8>,         // 4 + 32 bytes
+}
+
+impl ReleaseCondition {
+    pub const MAX_EXPECTED_LEN: usize = 32;
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 4 + 4 + Self::MAX_EXPECTED_LEN;
+}
+
+/// A reward drop to a campaign's backers with a claim window, at
+/// `[b"distribution", campaign, distribution_id]`. Tokens wait in `[b"distribution_vault", distribution]`.
+#[account]
+pub struct Distribution {
+    pub campaign: Pubkey,          // 32 bytes
+    pub distribution_id: u32,      // 4 bytes
+    pub reward_mint: Pubkey,       // 32 bytes
+    pub total_amount: u64,         // 8 bytes
+    pub raised_basis: u64,         // 8 bytes
+    pub claim_start: i64,          // 8 bytes
+    pub claim_end: i64,            // 8 bytes
+    pub unclaimed_to_treasury: bool, // 1 byte
+    pub claimed_amount: u64,       // 8 bytes
+    pub claims_count: u32,         // 4 bytes
+    pub unclaimed_amount: u64,     // 8 bytes
+    pub is_closed: bool,           // 1 byte
+}
+
+impl Distribution {
+    pub const SIZE: usize = 8 + 32 + 4 + 32 + 8 + 8 + 8 + 8 + 1 + 8 + 4 + 8 + 1;
+
+    /// A backer's share of the drop, proportional to what they contributed.
+    pub fn allocation_for(&self, contributed: u64) -> u64 {
+        if self.raised_basis == 0 {
+            return 0;
+        }
+        (self.total_amount as u128 * contributed as u128 / self.raised_basis as u128) as u64
+    }
+}
+
+/// Proof a backer has claimed from a distribution.
+#[account]
+pub struct DistributionClaim {
+    pub distribution: Pubkey,      // 32 bytes
+    pub backer: Pubkey,            // 32 bytes
+    pub amount: u64,               // 8 bytes
+}
+
+impl DistributionClaim {
+    pub const SIZE: usize = 8 + 32 + 32 + 8;
+}
+
+/// Small fixed-layout snapshot of a campaign, at `[b"status", campaign]`, for cheap polling by
+/// other programs (e.g. a lending market valuing campaign positions). Check `updated_slot`
+/// for staleness; `publish_status` is permissionless.
+#[account]
+pub struct StatusFeed {
+    pub campaign: Pubkey,          // 32 bytes
+    pub funded_bps: u16,           // 2 bytes (10_000 = target reached, saturates at u16::MAX)
+    pub backers: u32,              // 4 bytes
+    pub seconds_left: u32,         // 4 bytes
+    pub flags: u8,                 // 1 byte
+    pub updated_slot: u64,         // 8 bytes
+}
+
+impl StatusFeed {
+    pub const SIZE: usize = 8 + 32 + 2 + 4 + 4 + 1 + 8;
+    pub const FLAG_SUCCESSFUL: u8 = 1 << 0;
+    pub const FLAG_WITHDRAWN: u8 = 1 << 1;
+    pub const FLAG_FROZEN: u8 = 1 << 2;
+    pub const FLAG_STALLED: u8 = 1 << 3;
+    pub const FLAG_ENDED: u8 = 1 << 4;
+}
+
+/// A grantee listed by the campaign operator; required by `initialize_campaign_for`.
+#[account]
+pub struct GrantCreator {
+    pub operator: Pubkey,          // 32 bytes
+    pub creator: Pubkey,           // 32 bytes
+    pub listed_at: i64,            // 8 bytes
+}
+
+impl GrantCreator {
+    pub const SIZE: usize = 8 + 32 + 32 + 8;
+}
+
+#[event]
+pub struct CampaignCreated {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub target_amount: u64,
+    pub end_time: i64,
+    pub grace_period_seconds: u64,
+    pub grace_max_shortfall_bps: u16,
+    pub decimals: u8,
+    pub mint_authority_active: bool,
+    pub freeze_authority_active: bool,
+    pub max_contributors: u32,
+}
+
+#[event]
+pub struct ContributionMade {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub total_raised: u64,
+    pub decimals: u8,
+    pub backer_index: u32,
+}
+
+#[event]
+pub struct CustodialContributionMade {
+    pub campaign: Pubkey,
+    pub custodian: Pubkey,
+    pub on_behalf_of: Pubkey,
+    pub identity_hash: [u8; 32],
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct FundsWithdrawn {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub fee_amount: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct ContributionRefunded {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct RentPoolFunded {
+    pub campaign: Pubkey,
+    pub sponsor: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct RentPoolClosed {
+    pub campaign: Pubkey,
+    pub sponsor: Pubkey,
+    pub accounts_sponsored: u32,
+    pub lamports_returned: u64,
+}
+
+#[event]
+pub struct CampaignArchived {
+    pub campaign: Pubkey,
+    pub summary: Pubkey,
+    pub raised_amount: u64,
+    pub is_successful: bool,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct ConfigInitialized {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct MintAllowlistUpdated {
+    pub mint: Pubkey,
+    pub allowed: bool,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct CustodianUpdated {
+    pub custodian: Pubkey,
+    pub approved: bool,
+}
+
+#[event]
+pub struct CampaignFrozen {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct SurveyAnswersCommitted {
+    pub campaign: Pubkey,
+    pub backer: Pubkey,
+    pub answers_hash: [u8; 32],
+}
+
+#[event]
+pub struct SurveyClosed {
+    pub campaign: Pubkey,
+    pub closed_at: i64,
+}
+
+#[event]
+pub struct ProrataRefundsStarted {
+    pub campaign: Pubkey,
+    pub vault_balance: u64,
+    pub outstanding: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct TemplateCreated {
+    pub template: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct SeriesCreated {
+    pub series: Pubkey,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct SeasonStarted {
+    pub series: Pubkey,
+    pub campaign: Pubkey,
+    pub season: u32,
+    pub cumulative_raised: u64,
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub effective_at: i64,
+}
+
+#[event]
+pub struct CampaignFeeOverrideSet {
+    pub campaign: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[event]
+pub struct RefundAddressSet {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub refund_address: Pubkey,
+}
+
+#[event]
+pub struct InactivityTimeoutSet {
+    pub campaign: Pubkey,
+    pub timeout_seconds: u64,
+}
+
+#[event]
+pub struct CampaignParametersUpdated {
+    pub campaign: Pubkey,
+    pub target_amount: u64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct ParametersLocked {
+    pub campaign: Pubkey,
+    pub target_amount: u64,
+    pub end_time: i64,
+    pub fee_override_bps: Option<u16>,
+    pub locked_at: i64,
+}
+
+#[event]
+pub struct CampaignStalled {
+    pub campaign: Pubkey,
+    pub triggered_by: Pubkey,
+    pub vault_balance: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct WithdrawalApproversSet {
+    pub campaign: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct WithdrawalApproved {
+    pub campaign: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u32,
+}
+
+#[event]
+pub struct ContributionCapSet {
+    pub campaign: Pubkey,
+    pub max_per_contributor: u64,
+    pub reputation_scaled: bool,
+}
+
+#[event]
+pub struct ReputationTierSet {
+    pub contributor: Pubkey,
+    pub tier: u8,
+}
+
+#[event]
+pub struct CampaignFinalized {
+    pub campaign: Pubkey,
+    pub is_successful: bool,
+    pub raised_amount: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct ContributionTagged {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub memo_hash: Option<[u8; 32]>,
+    pub referrer: Option<Pubkey>,
+    pub funding_reference: Option<Pubkey>,
+    pub variant: Option<u8>,
+}
+
+#[event]
+pub struct ComposableProgramUpdated {
+    pub program_id: Pubkey,
+    pub approved: bool,
+}
+
+#[event]
+pub struct CampaignCategorySet {
+    pub campaign: Pubkey,
+    pub category: u8,
+}
+
+#[event]
+pub struct RoundCreated {
+    pub round: Pubkey,
+    pub authority: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub eligible_category: u8,
+}
+
+#[event]
+pub struct RoundFunded {
+    pub round: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub matching_pool: u64,
+}
+
+#[event]
+pub struct CampaignEnrolled {
+    pub round: Pubkey,
+    pub campaign: Pubkey,
 }
 
-#[derive(Accounts)]
-pub struct RefundContribution<'info> {
-    pub campaign: Account<'info, Campaign>,
-    
-    #[account(
-        mut,
-        seeds = [b"contribution", campaign.key().as_ref(), contributor.key().as_ref()],
-        bump
-    )]
-    pub contribution: Account<'info, Contribution>,
-    
-    #[account(
-        mut,
-        seeds = [b"vault", campaign.key().as_ref()],
-        bump
-    )]
-    pub campaign_vault: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub contributor_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
-    pub contributor: Signer<'info>,
-    
-    pub token_program: Program<'info, Token>,
+#[event]
+pub struct RoundContributionTagged {
+    pub round: Pubkey,
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MatchDisbursed {
+    pub round: Pubkey,
+    pub campaign: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct RoundRemainderReclaimed {
+    pub round: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RoundWeightSourceSet {
+    pub round: Pubkey,
+    pub attestor: Pubkey,
+    pub default_weight_bps: u16,
+}
+
+#[event]
+pub struct TrustWeightSet {
+    pub attestor: Pubkey,
+    pub contributor: Pubkey,
+    pub weight_bps: u16,
+}
+
+#[event]
+pub struct PlatformMintSet {
+    pub platform_mint: Pubkey,
+}
+
+#[event]
+pub struct BoostStaked {
+    pub campaign: Pubkey,
+    pub amount: u64,
+    pub featured_weight: u64,
+}
+
+#[event]
+pub struct BoostUnstaked {
+    pub campaign: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PointsConfigUpdated {
+    pub accrual_bps: u16,
+    pub points_per_discount_bps: u64,
+    pub max_fee_discount_bps: u16,
+    pub badge_cost: u64,
+}
+
+#[event]
+pub struct PointsAccrued {
+    pub contributor: Pubkey,
+    pub campaign: Pubkey,
+    pub points: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct PointsRedeemed {
+    pub contributor: Pubkey,
+    pub points: u64,
+    pub reward: PointsReward,
+}
+
+#[event]
+pub struct TeamMemberUpdated {
+    pub campaign: Pubkey,
+    pub member: Pubkey,
+    pub roles: u8,
+}
+
+#[event]
+pub struct CampaignUpdatePosted {
+    pub campaign: Pubkey,
+    pub author: Pubkey,
+    pub content_hash: [u8; 32],
+}
+
+#[event]
+pub struct CampaignMetadataEdited {
+    pub campaign: Pubkey,
+    pub editor: Pubkey,
+}
+
+#[event]
+pub struct BackersAcknowledged {
+    pub campaign: Pubkey,
+    pub page: u32,
+    pub added: u32,
+    pub total_on_page: u32,
+}
+
+#[event]
+pub struct LocalizedMetadataSet {
+    pub campaign: Pubkey,
+    pub locale: String,
+    pub uri_hash: [u8; 32],
+}
+
+#[event]
+pub struct HolderGateSet {
+    pub campaign: Pubkey,
+    pub gate_mint: Pubkey,
+    pub gate_is_collection: bool,
+    pub priority_ends_at: i64,
+}
+
+#[event]
+pub struct HolderOnlySet {
+    pub campaign: Pubkey,
+    pub holder_only: bool,
+}
+
+#[event]
+pub struct StreakBonusSet {
+    pub campaign: Pubkey,
+    pub streak_bonus_bps: u16,
+}
+
+#[event]
+pub struct CampaignFollowed {
+    pub campaign: Pubkey,
+    pub follower: Pubkey,
+    pub followers_count: u32,
+}
+
+#[event]
+pub struct CampaignUnfollowed {
+    pub campaign: Pubkey,
+    pub follower: Pubkey,
+    pub followers_count: u32,
+}
+
+#[event]
+pub struct VaultAuthoritiesRepaired {
+    pub campaign: Pubkey,
+    pub cleared_delegate: bool,
+    pub cleared_close_authority: bool,
+}
+
+#[event]
+pub struct RevenueShareConfigured {
+    pub reward_mint: Pubkey,
+    pub share_bps: u16,
+}
+
+#[event]
+pub struct RevenueShared {
+    pub campaign: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct PlatformTokensStaked {
+    pub staker: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct PlatformTokensUnstaked {
+    pub staker: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+}
+
+#[event]
+pub struct RevenueShareClaimed {
+    pub staker: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FaqUpdated {
+    pub campaign: Pubkey,
+    pub editor: Pubkey,
+    pub faq_hash: [u8; 32],
+    pub faq_version: u32,
+}
+
+#[event]
+pub struct CampaignScheduled {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct AntiSnipingSet {
+    pub campaign: Pubkey,
+    pub window_seconds: u32,
+    pub extension_seconds: u32,
+    pub max_total_extension_seconds: u32,
+}
+
+#[event]
+pub struct DeadlineExtended {
+    pub campaign: Pubkey,
+    pub extension_seconds: u32,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct ContributionCooldownSet {
+    pub campaign: Pubkey,
+    pub cooldown_threshold: u64,
+    pub cooldown_seconds: u32,
+}
+
+#[event]
+pub struct ContributionQueued {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub release_at: i64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct PendingContributionResolved {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub credited: bool,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct ContentKeyHashSet {
+    pub campaign: Pubkey,
+    pub content_key_hash: [u8; 32],
+}
+
+#[event]
+pub struct BackerProven {
+    pub campaign: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+    pub content_key_hash: [u8; 32],
+    pub challenge: [u8; 32],
+    pub proven_at: i64,
+}
+
+#[event]
+pub struct AdminActionRecorded {
+    pub index: u64,
+    pub actor: Pubkey,
+    pub action: AdminActionKind,
+    pub target: Pubkey,
+}
+
+#[event]
+pub struct TimelockUpdated {
+    pub timelock_seconds: i64,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub available_at: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct MintMinimumTargetSet {
+    pub mint: Pubkey,
+    pub min_target_amount: u64,
+}
+
+#[event]
+pub struct CampaignVerificationSet {
+    pub campaign: Pubkey,
+    pub verified: bool,
+}
+
+#[event]
+pub struct CharityFeeWaiverSet {
+    pub enabled: bool,
+    pub charity_category: u8,
+}
+
+#[event]
+pub struct DelegatedContributionMade {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct PostMortemRequirementSet {
+    pub required: bool,
+}
+
+#[event]
+pub struct PostMortemPosted {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub post_mortem_hash: [u8; 32],
+}
+
+#[event]
+pub struct SurplusPolicySet {
+    pub policy: SurplusPolicy,
+}
+
+#[event]
+pub struct VaultReconciled {
+    pub campaign: Pubkey,
+    pub policy: SurplusPolicy,
+    pub surplus: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct VariantMetadataSet {
+    pub campaign: Pubkey,
+    pub variant: u8,
+    pub uri_hash: [u8; 32],
+}
+
+#[event]
+pub struct ParentCampaignSet {
+    pub campaign: Pubkey,
+    pub parent: Option<Pubkey>,
+    pub share_bps: u16,
+}
+
+#[event]
+pub struct BundleRolledUp {
+    pub parent: Pubkey,
+    pub child: Pubkey,
+    pub total_raised: u64,
+    pub total_backers: u64,
+    pub decimals: u8,
 }
 
-#[account]
-pub struct Campaign {
-    pub creator: Pubkey,           // 32 bytes
-    pub title: String,             // 4 + 100 bytes
-    pub description: String,       // 4 + 500 bytes
-    pub target_amount: u64,        // 8 bytes
-    pub current_amount: u64,       // 8 bytes
-    pub start_time: i64,           // 8 bytes
-    pub end_time: i64,             // 8 bytes
-    pub is_successful: bool,       // 1 byte
-    pub is_withdrawn: bool,        // 1 byte
-    pub contributors_count: u32,   // 4 bytes
+#[event]
+pub struct ParentShareRouted {
+    pub campaign: Pubkey,
+    pub parent: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
 }
 
-impl Campaign {
-    pub const SIZE: usize = 8 + 32 + 4 + 100 + 4 + 500 + 8 + 8 + 8 + 8 + 1 + 1 + 4;
+#[event]
+pub struct ReleaseConditionSet {
+    pub campaign: Pubkey,
+    pub account: Pubkey,
+    pub owner_program: Pubkey,
+    pub offset: u32,
+    pub expected: Vec<u8>,
 }
 
-#[account]
-pub struct Contribution {
-    pub contributor: Pubkey,       // 32 bytes
-    pub campaign: Pubkey,          // 32 bytes
-    pub amount: u64,               // 8 bytes
+#[event]
+pub struct ReleaseConditionCleared {
+    pub campaign: Pubkey,
 }
 
-impl Contribution {
-    pub const SIZE: usize = 8 + 32 + 32 + 8;
+#[event]
+pub struct DistributionCreated {
+    pub campaign: Pubkey,
+    pub distribution: Pubkey,
+    pub reward_mint: Pubkey,
+    pub total_amount: u64,
+    pub claim_start: i64,
+    pub claim_end: i64,
 }
 
 #[event]
-pub struct CampaignCreated {
+pub struct DistributionClaimed {
+    pub distribution: Pubkey,
+    pub backer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DistributionWindowClosed {
+    pub campaign: Pubkey,
+    pub distribution: Pubkey,
+    pub claimed_amount: u64,
+    pub unclaimed_amount: u64,
+    pub to_treasury: bool,
+}
+
+#[event]
+pub struct RecoveryKeySet {
+    pub campaign: Pubkey,
+    pub recovery_key: Pubkey,
+}
+
+#[event]
+pub struct CreatorFrozen {
     pub campaign: Pubkey,
     pub creator: Pubkey,
-    pub target_amount: u64,
-    pub end_time: i64,
 }
 
 #[event]
-pub struct ContributionMade {
+pub struct CreatorKeyRotated {
+    pub campaign: Pubkey,
+    pub old_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct RefundDeferred {
     pub campaign: Pubkey,
     pub contributor: Pubkey,
     pub amount: u64,
-    pub total_raised: u64,
+    pub destination_frozen: bool,
+    pub decimals: u8,
 }
 
 #[event]
-pub struct FundsWithdrawn {
+pub struct MintAuthorityPolicySet {
+    pub forbid: bool,
+}
+
+#[event]
+pub struct CampaignOperatorSet {
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct CampaignCreatedByOperator {
     pub campaign: Pubkey,
     pub creator: Pubkey,
-    pub amount: u64,
+    pub operator: Pubkey,
 }
 
 #[event]
-pub struct ContributionRefunded {
+pub struct GrantCreatorUpdated {
+    pub operator: Pubkey,
+    pub creator: Pubkey,
+    pub listed: bool,
+}
+
+#[event]
+pub struct CampaignAccepted {
     pub campaign: Pubkey,
-    pub contributor: Pubkey,
+    pub creator: Pubkey,
+    pub operator: Pubkey,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct CampaignRejected {
+    pub campaign: Pubkey,
+    pub creator: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct RentReimbursementSet {
+    pub campaign: Pubkey,
+    pub per_account: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct RentReimbursed {
+    pub campaign: Pubkey,
+    pub sponsor: Pubkey,
     pub amount: u64,
+    pub decimals: u8,
 }
 
 #[error_code]
@@ -420,4 +10299,531 @@ pub enum CrowdfundingError {
     
     #[msg("Campaign funds already withdrawn")]
     CampaignAlreadyWithdrawn,
-}
\ No newline at end of file
+    
+    #[msg("Unauthorized archival")]
+    UnauthorizedArchival,
+    
+    #[msg("Campaign is not settled yet")]
+    CampaignNotSettled,
+    
+    #[msg("Campaign vault still holds funds")]
+    VaultNotEmpty,
+    
+    #[msg("Invalid sponsorship amount")]
+    InvalidSponsorshipAmount,
+    
+    #[msg("Only the rent pool sponsor can do this")]
+    UnauthorizedSponsor,
+    
+    #[msg("Unauthorized admin action")]
+    UnauthorizedAdmin,
+    
+    #[msg("Mint is not on the allowlist")]
+    MintNotAllowlisted,
+    
+    #[msg("Mint is already on the allowlist")]
+    MintAlreadyAllowlisted,
+    
+    #[msg("Mint allowlist is full")]
+    MintAllowlistFull,
+    
+    #[msg("Campaign is frozen")]
+    CampaignFrozen,
+    
+    #[msg("Campaign is not frozen")]
+    CampaignNotFrozen,
+    
+    #[msg("Refund destination is not owned by the contributor")]
+    InvalidRefundDestination,
+    
+    #[msg("Contribution record belongs to a different custodian")]
+    CustodianMismatch,
+    
+    #[msg("Identity hash does not match the contribution record")]
+    IdentityHashMismatch,
+    
+    #[msg("Unauthorized refund")]
+    UnauthorizedRefund,
+    
+    #[msg("Survey is closed")]
+    SurveyClosed,
+    
+    #[msg("Only backers can do this")]
+    NotABacker,
+    
+    #[msg("Only the campaign creator can do this")]
+    UnauthorizedCreator,
+    
+    #[msg("Invalid grace period (max 7 days, shortfall up to 10000 bps)")]
+    InvalidGracePeriod,
+    
+    #[msg("Vault cannot cover all refunds; start pro-rata refunds first")]
+    RefundsUndercollateralized,
+    
+    #[msg("Pro-rata refunds already started")]
+    ProrataRefundsAlreadyStarted,
+    
+    #[msg("Vault covers all outstanding contributions")]
+    VaultFullyCollateralized,
+    
+    #[msg("Template name is too long (max 32 characters)")]
+    TemplateNameTooLong,
+    
+    #[msg("Only the template authority can use this template")]
+    UnauthorizedTemplateUse,
+    
+    #[msg("Series name is too long (max 32 characters)")]
+    SeriesNameTooLong,
+    
+    #[msg("Previous season campaign must be provided")]
+    PreviousSeasonRequired,
+    
+    #[msg("Previous season is not settled yet")]
+    PreviousSeasonNotSettled,
+    
+    #[msg("Invalid fee (max 1000 bps)")]
+    InvalidFeeBps,
+    
+    #[msg("Treasury token account is not owned by the configured treasury")]
+    InvalidTreasuryAccount,
+    
+    #[msg("Campaign parameters are locked after the first contribution")]
+    ParametersLocked,
+    
+    #[msg("Campaign was marked stalled")]
+    CampaignStalled,
+    
+    #[msg("Campaign has no inactivity timeout")]
+    InactivityTimeoutNotSet,
+    
+    #[msg("Campaign was not successful")]
+    CampaignNotSuccessful,
+    
+    #[msg("Creator inactivity timeout has not elapsed")]
+    CreatorStillActive,
+    
+    #[msg("Withdrawal requires approvals; use execute_withdrawal")]
+    WithdrawalApprovalRequired,
+    
+    #[msg("Invalid approver set")]
+    InvalidApproverSet,
+    
+    #[msg("Signer is not a withdrawal approver")]
+    NotAnApprover,
+    
+    #[msg("Not enough withdrawal approvals")]
+    InsufficientApprovals,
+    
+    #[msg("Contribution exceeds the per-contributor cap")]
+    ContributorCapExceeded,
+    
+    #[msg("Invalid reputation tier (max 4)")]
+    InvalidReputationTier,
+    
+    #[msg("Unsupported instruction arguments version")]
+    UnsupportedArgsVersion,
+    
+    #[msg("Contributors cannot refer themselves")]
+    SelfReferral,
+    
+    #[msg("Contributor token account is owned by a program that is not allowlisted")]
+    TokenOwnerNotAllowlisted,
+    
+    #[msg("Round name too long (max 32 characters)")]
+    RoundNameTooLong,
+    
+    #[msg("Round must end after it starts and in the future")]
+    InvalidRoundWindow,
+    
+    #[msg("Matching round has ended")]
+    RoundEnded,
+    
+    #[msg("Matching round is not active")]
+    RoundNotActive,
+    
+    #[msg("Matching round has not ended yet")]
+    RoundNotEnded,
+    
+    #[msg("Campaign category is not eligible for this round")]
+    CategoryNotEligible,
+    
+    #[msg("Campaign mint does not match the round mint")]
+    RoundMintMismatch,
+    
+    #[msg("Only the round authority can perform this action")]
+    UnauthorizedRoundAuthority,
+    
+    #[msg("Round accounts must be passed as (enrollment, campaign, vault) for this round")]
+    InvalidRoundBatch,
+    
+    #[msg("Not every enrolled campaign has been settled")]
+    RoundNotSettled,
+    
+    #[msg("Matching round has already started")]
+    RoundAlreadyStarted,
+    
+    #[msg("Trust weight cannot exceed 10000 basis points")]
+    InvalidTrustWeight,
+    
+    #[msg("Token account balance changed by an unexpected amount during a transfer")]
+    UnexpectedBalanceAfterTransfer,
+    
+    #[msg("Stake amount must be greater than zero")]
+    InvalidStakeAmount,
+    
+    #[msg("Invalid points program configuration")]
+    InvalidPointsConfig,
+    
+    #[msg("Fee discount must be positive and within the points program maximum")]
+    InvalidFeeDiscount,
+    
+    #[msg("Not enough points for this reward")]
+    InsufficientPoints,
+    
+    #[msg("Team roles must be a non-empty combination of known role bits")]
+    InvalidTeamRoles,
+    
+    #[msg("Signer is neither the creator nor a team member with the required role")]
+    MissingTeamRole,
+    
+    #[msg("Payouts triggered by team members must go to a creator-owned token account")]
+    InvalidPayoutAccount,
+    
+    #[msg("Acknowledgment batch must not be empty")]
+    InvalidAcknowledgmentBatch,
+    
+    #[msg("Acknowledgment page has no room for this batch")]
+    AcknowledgmentPageFull,
+    
+    #[msg("Previous acknowledgment page must be full before starting a new one")]
+    PreviousPageNotFull,
+    
+    #[msg("Locale must be 1-16 characters")]
+    InvalidLocale,
+    
+    #[msg("Client order id must not be all zeros")]
+    InvalidClientOrderId,
+    
+    #[msg("This client order id was already credited")]
+    DuplicateClientOrderId,
+    
+    #[msg("Priority window must end before the campaign does")]
+    InvalidPriorityWindow,
+    
+    #[msg("Contributions are holder-only right now; pass a gate token account")]
+    GateProofRequired,
+    
+    #[msg("Gate token account or NFT metadata does not prove membership")]
+    InvalidGateProof,
+    
+    #[msg("Set a holder gate before making the campaign holder-only")]
+    HolderGateNotSet,
+    
+    #[msg("Streak bonus cannot exceed 10000 basis points per day")]
+    InvalidStreakBonus,
+    
+    #[msg("Batch entries must match (campaign, contribution, vault) accounts, at most 8 per batch")]
+    InvalidContributionBatch,
+    
+    #[msg("Vault has an unexpected owner, delegate or close authority")]
+    UnexpectedVaultAuthority,
+    
+    #[msg("Revenue share cannot exceed 10000 basis points")]
+    InvalidRevenueShare,
+    
+    #[msg("Revenue vault is required while the revenue share covers this mint")]
+    RevenueShareAccountsRequired,
+    
+    #[msg("No revenue share to claim")]
+    NothingToClaim,
+    
+    #[msg("Scheduled start must be in the future and at most 90 days away")]
+    InvalidStartTime,
+    
+    #[msg("Campaign has not started yet")]
+    CampaignNotStarted,
+    
+    #[msg("Anti-sniping window and extension are capped at 1 day, total extension at 7 days")]
+    InvalidAntiSnipingRule,
+    
+    #[msg("Contributions this large must be queued with queue_contribution")]
+    CooldownRequired,
+    
+    #[msg("Contribution is below the cool-down threshold; contribute directly")]
+    CooldownNotRequired,
+    
+    #[msg("Cool-down period has not elapsed yet")]
+    CooldownNotElapsed,
+    
+    #[msg("Cool-down cannot exceed 3 days")]
+    InvalidCooldown,
+    
+    #[msg("Campaign has no backer content key")]
+    ContentKeyNotSet,
+    
+    #[msg("Timelock can only grow, up to 30 days")]
+    InvalidTimelock,
+    
+    #[msg("New admin cannot be the default pubkey")]
+    InvalidAdmin,
+    
+    #[msg("No admin transfer is pending")]
+    NoPendingAdmin,
+    
+    #[msg("Timelock has not elapsed yet")]
+    TimelockNotElapsed,
+    
+    #[msg("Target amount is below the minimum for this mint")]
+    TargetBelowMintMinimum,
+    
+    #[msg("Mint minimum entry is not owned by this program")]
+    InvalidMintMinimum,
+    
+    #[msg("Charity fee waiver needs a non-zero category")]
+    InvalidCharityCategory,
+    
+    #[msg("Contribution sequence does not match the record")]
+    ContributionSeqMismatch,
+    
+    #[msg("Pre-signed contribution is past its validity window")]
+    ContributionExpired,
+    
+    #[msg("Signer is not an approved delegate for this amount")]
+    DelegateNotApproved,
+    
+    #[msg("Post the failed campaign's post-mortem before launching again")]
+    PostMortemRequired,
+    
+    #[msg("Post-mortems are only for finalized campaigns that did not succeed")]
+    PostMortemNotApplicable,
+    
+    #[msg("Post-mortem hash cannot be empty")]
+    InvalidPostMortem,
+    
+    #[msg("Post-mortem has already been posted")]
+    PostMortemAlreadyPosted,
+    
+    #[msg("Creator profile is not owned by this program")]
+    InvalidCreatorProfile,
+    
+    #[msg("Too many search keywords")]
+    TooManyKeywords,
+    
+    #[msg("Search keywords must be 1 to 32 bytes")]
+    InvalidKeyword,
+    
+    #[msg("Refund destination holds a different mint than the vault")]
+    RefundMintMismatch,
+    
+    #[msg("Vault holds no untracked surplus")]
+    NoVaultSurplus,
+    
+    #[msg("Surplus destination does not match the policy")]
+    InvalidSurplusDestination,
+    
+    #[msg("Surplus can only go to backers once refunds are open")]
+    SurplusPolicyUnavailable,
+    
+    #[msg("Mint metadata account is invalid or for another mint")]
+    InvalidMintMetadata,
+    
+    #[msg("Unknown campaign variant")]
+    InvalidVariant,
+    
+    #[msg("Parent campaign is invalid for this bundle")]
+    InvalidParentCampaign,
+    
+    #[msg("Parent share exceeds the maximum")]
+    InvalidParentShare,
+    
+    #[msg("Parent campaign and vault accounts are required")]
+    ParentAccountsRequired,
+    
+    #[msg("Release condition must expect 1 to 32 bytes")]
+    InvalidReleaseCondition,
+    
+    #[msg("Release condition and its external account are required")]
+    ReleaseConditionAccountsRequired,
+    
+    #[msg("External release condition is not met")]
+    ReleaseConditionNotMet,
+    
+    #[msg("Backer shares are only final after the creator withdraws")]
+    BackerSetNotFinal,
+    
+    #[msg("Distribution amount must be greater than 0")]
+    InvalidDistributionAmount,
+    
+    #[msg("Claim window must end after it starts and in the future")]
+    InvalidClaimWindow,
+    
+    #[msg("Claim window is not open")]
+    ClaimWindowNotOpen,
+    
+    #[msg("Claim window has not ended yet")]
+    ClaimWindowStillOpen,
+    
+    #[msg("Distribution is already closed")]
+    DistributionClosed,
+    
+    #[msg("Token account is not a valid destination for this distribution")]
+    InvalidDistributionDestination,
+    
+    #[msg("Recovery key is already set")]
+    RecoveryKeyAlreadySet,
+    
+    #[msg("Recovery key is missing or does not match")]
+    InvalidRecoveryKey,
+    
+    #[msg("New creator key is invalid")]
+    InvalidCreatorKey,
+    
+    #[msg("Creator has frozen withdrawals pending key rotation")]
+    CreatorFrozen,
+    
+    #[msg("Refund destination token account is frozen")]
+    RefundDestinationFrozen,
+    
+    #[msg("Refund destination token account does not exist")]
+    RefundDestinationMissing,
+    
+    #[msg("Refund destination can receive funds; use refund_contribution")]
+    RefundDestinationUsable,
+    
+    #[msg("No deferred refund to retry")]
+    NoDeferredRefund,
+    
+    #[msg("Campaign vault is frozen by the mint's freeze authority")]
+    VaultFrozen,
+    
+    #[msg("Contributor token account is frozen by the mint's freeze authority")]
+    ContributorAccountFrozen,
+    
+    #[msg("Mint has an active mint or freeze authority")]
+    MintAuthorityForbidden,
+    
+    #[msg("Campaign has reached its maximum number of contributors")]
+    ContributorLimitReached,
+    
+    #[msg("Only the platform's campaign operator can create campaigns for others")]
+    UnauthorizedOperator,
+    
+    #[msg("Creator was listed by a different campaign operator")]
+    GrantCreatorOperatorMismatch,
+    
+    #[msg("Campaign is waiting for its creator to accept it")]
+    CampaignPendingAcceptance,
+    
+    #[msg("Campaign is not waiting for acceptance")]
+    CampaignNotPendingAcceptance,
+    
+    #[msg("Approvals were given for a previous approver list")]
+    StaleApprovals,
+    
+    #[msg("Campaign address belongs to an archived campaign")]
+    CampaignAddressArchived,
+    
+    #[msg("Backers still have unclaimed refunds")]
+    RefundsOutstanding,
+    
+    #[msg("A rent pool, boost, reward drop or pending contribution still needs the campaign")]
+    CampaignAccountsOutstanding,
+    
+    #[msg("Rent pool and sponsor token account are required to reimburse the sponsor")]
+    RentPoolAccountsRequired,
+    
+    #[msg("Rent sponsor has not been reimbursed yet")]
+    RentReimbursementPending,
+    
+    #[msg("Parent campaign is still collecting")]
+    ParentNotSettled,
+    
+    #[msg("Parent campaign raises a different mint than the child")]
+    ParentMintMismatch,
+    
+    #[msg("Trust attestation account is not owned by this program")]
+    InvalidTrustAttestation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token::state::{Account as SplAccount, AccountState};
+
+    fn vault_account(owner: Pubkey, delegate: COption<Pubkey>, close_authority: COption<Pubkey>) -> TokenAccount {
+        let account = SplAccount {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount: 1_000,
+            delegate,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: if delegate.is_some() { 1_000 } else { 0 },
+            close_authority,
+        };
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount::pack(account, &mut data).unwrap();
+        TokenAccount::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn clean_vault_passes_authority_check() {
+        let vault_key = Pubkey::new_unique();
+        let vault = vault_account(vault_key, COption::None, COption::None);
+
+        assert!(check_token_authorities(&vault_key, &vault).is_ok());
+        assert_eq!(unexpected_vault_authorities(&vault), (false, false));
+    }
+
+    #[test]
+    fn vault_with_delegate_is_rejected() {
+        let vault_key = Pubkey::new_unique();
+        let vault = vault_account(vault_key, COption::Some(Pubkey::new_unique()), COption::None);
+
+        assert_eq!(
+            check_token_authorities(&vault_key, &vault).unwrap_err(),
+            CrowdfundingError::UnexpectedVaultAuthority.into()
+        );
+        assert_eq!(unexpected_vault_authorities(&vault), (true, false));
+    }
+
+    #[test]
+    fn vault_with_close_authority_is_rejected() {
+        let vault_key = Pubkey::new_unique();
+        let vault = vault_account(vault_key, COption::None, COption::Some(Pubkey::new_unique()));
+
+        assert_eq!(
+            check_token_authorities(&vault_key, &vault).unwrap_err(),
+            CrowdfundingError::UnexpectedVaultAuthority.into()
+        );
+        assert_eq!(unexpected_vault_authorities(&vault), (false, true));
+    }
+
+    #[test]
+    fn vault_owned_by_another_key_is_rejected() {
+        let vault_key = Pubkey::new_unique();
+        let vault = vault_account(Pubkey::new_unique(), COption::None, COption::None);
+
+        assert_eq!(
+            check_token_authorities(&vault_key, &vault).unwrap_err(),
+            CrowdfundingError::UnexpectedVaultAuthority.into()
+        );
+        // Ownership isn't something repair can fix, so it clears nothing
+        assert_eq!(unexpected_vault_authorities(&vault), (false, false));
+    }
+
+    #[test]
+    fn vault_with_delegate_and_close_authority_flags_both() {
+        let vault_key = Pubkey::new_unique();
+        let vault = vault_account(
+            vault_key,
+            COption::Some(Pubkey::new_unique()),
+            COption::Some(Pubkey::new_unique()),
+        );
+
+        assert!(check_token_authorities(&vault_key, &vault).is_err());
+        assert_eq!(unexpected_vault_authorities(&vault), (true, true));
+    }
+}